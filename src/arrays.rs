@@ -0,0 +1,237 @@
+use crate::{BoundedIx, Bounds, CompositeIx, Contains, Ix};
+use core::array;
+
+/// An iterator over a range of arrays, in row-major order.
+///
+/// This is the [`Range`] type of the [`Ix`] implementation for arrays.
+/// The last component varies the fastest.
+/// A range of arrays of length zero consists of exactly one (empty) array.
+///
+/// [`Range`]: Ix::Range
+#[derive(Clone, Debug)]
+pub struct ArrayRange<T, const N: usize> {
+    next: Option<[T; N]>,
+    min: [T; N],
+    max: [T; N],
+}
+
+impl<T: Ix + Clone, const N: usize> Iterator for ArrayRange<T, N> {
+    type Item = [T; N];
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        self.next = current
+            .clone()
+            .successor(self.min.clone(), self.max.clone());
+        Some(current)
+    }
+}
+
+/// Arrays are indexed like tuples whose components all have the same type.
+/// In particular, `[T; 0]` behaves like `()`: every range of it has exactly one element.
+impl<T: Ix + Clone, const N: usize> Ix for [T; N] {
+    type Range = ArrayRange<T, N>;
+    fn range(min: Self, max: Self) -> Self::Range {
+        for (min, max) in min.iter().zip(&max) {
+            assert_ordered!(min, max);
+        }
+        ArrayRange {
+            next: Some(min.clone()),
+            min,
+            max,
+        }
+    }
+    fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+        let mut index = 0usize;
+        for ((ix, min), max) in self.into_iter().zip(min).zip(max) {
+            index = index
+                .checked_mul(T::range_size_checked(min.clone(), max.clone())?)?
+                .checked_add(ix.index_checked(min, max)?)?;
+        }
+        Some(index)
+    }
+    fn in_range(self, min: Self, max: Self) -> bool {
+        self.into_iter()
+            .zip(min)
+            .zip(max)
+            .all(|((ix, min), max)| ix.in_range(min, max))
+    }
+    fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+        let mut size = 1usize;
+        for (min, max) in min.into_iter().zip(max) {
+            size = size.checked_mul(T::range_size_checked(min, max)?)?;
+        }
+        Some(size)
+    }
+    fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+        let mut index = index;
+        let mut ix = min.clone();
+        for k in (0..N).rev() {
+            let i = match T::range_size_checked(min[k].clone(), max[k].clone()) {
+                Some(size) => {
+                    let i = index % size;
+                    index /= size;
+                    i
+                }
+                None => core::mem::take(&mut index),
+            };
+            ix[k] = T::from_index_checked(i, min[k].clone(), max[k].clone())?;
+        }
+        if index == 0 {
+            Some(ix)
+        } else {
+            None
+        }
+    }
+    fn index_u128(self, min: Self, max: Self) -> u128 {
+        let mut index = 0u128;
+        for ((ix, min), max) in self.into_iter().zip(min).zip(max) {
+            let size = T::extent_u128(min.clone(), max.clone()).checked_add(1);
+            index = match (index, size) {
+                (0, _) => 0,
+                (index, Some(size)) => index.checked_mul(size).expect("index too large"),
+                (_, None) => panic!("index too large"),
+            }
+            .checked_add(ix.index_u128(min, max))
+            .expect("index too large");
+        }
+        index
+    }
+    fn manhattan_distance(self, other: Self, min: Self, max: Self) -> u128 {
+        let mut distance = 0u128;
+        for (((ix, other), min), max) in self.into_iter().zip(other).zip(min).zip(max) {
+            distance = distance
+                .checked_add(ix.manhattan_distance(other, min, max))
+                .expect("distance too large");
+        }
+        distance
+    }
+    fn successor(self, min: Self, max: Self) -> Option<Self> {
+        let mut ix = self;
+        for k in (0..N).rev() {
+            match ix[k].clone().successor(min[k].clone(), max[k].clone()) {
+                Some(next) => {
+                    ix[k] = next;
+                    return Some(ix);
+                }
+                None => ix[k] = min[k].clone(),
+            }
+        }
+        None
+    }
+    fn predecessor(self, min: Self, max: Self) -> Option<Self> {
+        let mut ix = self;
+        for k in (0..N).rev() {
+            match ix[k].clone().predecessor(min[k].clone(), max[k].clone()) {
+                Some(previous) => {
+                    ix[k] = previous;
+                    return Some(ix);
+                }
+                None => ix[k] = max[k].clone(),
+            }
+        }
+        None
+    }
+    fn merge(a: (Self, Self), b: (Self, Self)) -> Option<(Self, Self)> {
+        if Ix::contains(a.clone(), Contains::Range(b.0.clone(), b.1.clone())) {
+            return Some(a);
+        }
+        if Ix::contains(b.clone(), Contains::Range(a.0.clone(), a.1.clone())) {
+            return Some(b);
+        }
+        // Otherwise, the boxes must coincide on every component but one.
+        let mut merged = a.clone();
+        let mut differing = 0;
+        for k in 0..N {
+            if a.0[k] != b.0[k] || a.1[k] != b.1[k] {
+                differing += 1;
+                (merged.0[k], merged.1[k]) = T::merge(
+                    (a.0[k].clone(), a.1[k].clone()),
+                    (b.0[k].clone(), b.1[k].clone()),
+                )?;
+            }
+        }
+        if differing == 1 {
+            Some(merged)
+        } else {
+            None
+        }
+    }
+    fn overlap_size_checked(a: (Self, Self), b: (Self, Self)) -> Option<usize> {
+        let ((a_min, a_max), (b_min, b_max)) = (a, b);
+        let sizes: [Option<usize>; N] = array::from_fn(|k| {
+            T::overlap_size_checked(
+                (a_min[k].clone(), a_max[k].clone()),
+                (b_min[k].clone(), b_max[k].clone()),
+            )
+        });
+        // An empty component makes the overlap empty, even if another one is too large.
+        if sizes.contains(&Some(0)) {
+            return Some(0);
+        }
+        sizes
+            .into_iter()
+            .try_fold(1usize, |size, component| size.checked_mul(component?))
+    }
+    fn bounds_sorted(a: Self, b: Self) -> Bounds<Self> {
+        let sorted: [(T, T); N] =
+            array::from_fn(|k| T::bounds_sorted(a[k].clone(), b[k].clone()).into_parts());
+        Bounds::new(
+            array::from_fn(|k| sorted[k].0.clone()),
+            array::from_fn(|k| sorted[k].1.clone()),
+        )
+    }
+}
+
+impl<T: BoundedIx + Clone, const N: usize> BoundedIx for [T; N] {
+    const MIN: Self = [T::MIN; N];
+    const MAX: Self = [T::MAX; N];
+    fn pad_bounds(min: Self, max: Self, n: usize) -> (Self, Self) {
+        let padded: [(T, T); N] =
+            array::from_fn(|k| T::pad_bounds(min[k].clone(), max[k].clone(), n));
+        (
+            array::from_fn(|k| padded[k].0.clone()),
+            array::from_fn(|k| padded[k].1.clone()),
+        )
+    }
+    fn shrink_bounds(min: Self, max: Self, n: usize) -> Option<(Self, Self)> {
+        let shrunk: [Option<(T, T)>; N] =
+            array::from_fn(|k| T::shrink_bounds(min[k].clone(), max[k].clone(), n));
+        if shrunk.iter().any(Option::is_none) {
+            return None;
+        }
+        let shrunk = shrunk.map(Option::unwrap);
+        Some((
+            array::from_fn(|k| shrunk[k].0.clone()),
+            array::from_fn(|k| shrunk[k].1.clone()),
+        ))
+    }
+    fn reindex_checked(self, min: Self, max: Self, to_min: Self) -> Option<Self> {
+        let mut ix = to_min.clone();
+        for k in 0..N {
+            ix[k] = self[k].clone().reindex_checked(
+                min[k].clone(),
+                max[k].clone(),
+                to_min[k].clone(),
+            )?;
+        }
+        Some(ix)
+    }
+}
+
+impl<T: Ix + Clone, const N: usize> CompositeIx for [T; N] {
+    type AxisSizes = [usize; N];
+    fn axis_sizes(min: Self, max: Self) -> Self::AxisSizes {
+        array::from_fn(|k| T::range_size(min[k].clone(), max[k].clone()))
+    }
+    fn axis_of(index: usize, axis: usize, min: Self, max: Self) -> usize {
+        assert!(axis < N, "axis out of range");
+        if let Some(size) = Ix::range_size_checked(min.clone(), max.clone()) {
+            assert!(index < size, "index out of range");
+        }
+        let mut index = index;
+        for k in (axis + 1..N).rev() {
+            index /= T::range_size(min[k].clone(), max[k].clone());
+        }
+        index % T::range_size(min[axis].clone(), max[axis].clone())
+    }
+}
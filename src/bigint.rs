@@ -0,0 +1,86 @@
+use num_bigint::{BigInt, BigUint};
+
+/// An iterator over a range of arbitrary-precision integers.
+///
+/// This is the [`Range`] type of the [`Ix`] implementations
+/// for [`BigInt`] and [`BigUint`].
+///
+/// [`Ix`]: crate::Ix
+/// [`Range`]: crate::Ix::Range
+#[derive(Clone, Debug)]
+pub struct BigRange<T> {
+    next: Option<T>,
+    max: T,
+}
+
+macro_rules! impl_ix_bigint {
+    ($($t: ty),+) => {
+        $(
+            impl Iterator for BigRange<$t> {
+                type Item = $t;
+                fn next(&mut self) -> Option<Self::Item> {
+                    let current = self.next.take()?;
+                    if current < self.max {
+                        self.next = Some(&current + 1u8);
+                    }
+                    Some(current)
+                }
+            }
+
+            impl $crate::Ix for $t {
+                type Range = BigRange<$t>;
+                fn range(min: Self, max: Self) -> Self::Range {
+                    assert_ordered!(min, max);
+                    BigRange {
+                        next: Some(min),
+                        max,
+                    }
+                }
+                fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    usize::try_from(&(self - min)).ok()
+                }
+                fn in_range(self, min: Self, max: Self) -> bool {
+                    assert_ordered!(min, max);
+                    min <= self && self <= max
+                }
+                fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+                    assert_ordered!(min, max);
+                    usize::try_from(&(max - min))
+                        .ok()
+                        .and_then(|n| n.checked_add(1))
+                }
+                fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+                    assert_ordered!(min, max);
+                    let ix = min + index;
+                    if ix > max {
+                        None
+                    } else {
+                        Some(ix)
+                    }
+                }
+                fn successor(self, min: Self, max: Self) -> Option<Self> {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    if self == max {
+                        None
+                    } else {
+                        Some(self + 1u8)
+                    }
+                }
+                fn predecessor(self, min: Self, max: Self) -> Option<Self> {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    if self == min {
+                        None
+                    } else {
+                        Some(self - 1u8)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_ix_bigint!(BigInt, BigUint);
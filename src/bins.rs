@@ -0,0 +1,50 @@
+use crate::{bucket_indices, Ix};
+
+/// An iterator over the bounds of the buckets of a range, as determined by [`Ix::quantize`].
+///
+/// This is returned by [`Ix::histogram_bins`].
+#[derive(Clone, Debug)]
+pub struct HistogramBins<I> {
+    min: I,
+    max: I,
+    size: usize,
+    buckets: usize,
+    bucket: usize,
+}
+
+impl<I: Ix + Clone> HistogramBins<I> {
+    pub(crate) fn new(min: I, max: I, buckets: usize) -> Self {
+        if buckets == 0 {
+            panic!("number of buckets is zero");
+        }
+        let size = Ix::range_size(min.clone(), max.clone());
+        HistogramBins {
+            min,
+            max,
+            size,
+            buckets,
+            bucket: 0,
+        }
+    }
+}
+
+impl<I: Ix + Clone> Iterator for HistogramBins<I> {
+    type Item = (I, I);
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.bucket < self.buckets {
+            let bucket = self.bucket;
+            self.bucket += 1;
+            if let Some((first, last)) = bucket_indices(bucket, self.size, self.buckets) {
+                return Some((
+                    Ix::from_index(first, self.min.clone(), self.max.clone()),
+                    Ix::from_index(last, self.min.clone(), self.max.clone()),
+                ));
+            }
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.buckets - self.bucket;
+        (0, Some(remaining.min(self.size)))
+    }
+}
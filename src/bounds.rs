@@ -0,0 +1,139 @@
+use crate::{Ix, RangeError};
+
+/// The bounds of a range, consisting of a least element and a greatest element.
+///
+/// A value of this type always upholds `min <= max`.
+///
+/// # Examples
+///
+/// ```
+/// # use ix_rs::{Bounds, Ix};
+/// let bounds = Bounds::new(-3i32, 3);
+/// assert_eq!(2.index_in(&bounds), 5);
+/// assert!(Ix::range_in(&bounds).eq(-3..=3));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds<I> {
+    min: I,
+    max: I,
+}
+
+impl<I: Ix> Bounds<I> {
+    /// Create the bounds of the range starting from `min` and stopping at `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub fn new(min: I, max: I) -> Self {
+        assert_ordered!(min, max);
+        Bounds { min, max }
+    }
+    /// Create the bounds of the range starting from `min` and stopping at `max`.
+    /// If `min` is greater than `max`, returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Bounds, RangeError};
+    /// assert_eq!(Bounds::try_new(0u8, 9), Ok(Bounds::new(0, 9)));
+    /// assert_eq!(Bounds::try_new(9u8, 0), Err(RangeError));
+    /// ```
+    pub fn try_new(min: I, max: I) -> Result<Self, RangeError> {
+        if min > max {
+            Err(RangeError)
+        } else {
+            Ok(Bounds { min, max })
+        }
+    }
+}
+
+impl<I: Ix + Clone> Bounds<I> {
+    /// Split the range into two halves at its midpoint.
+    /// If the size of the range is odd, the first half has one more element than the second half.
+    /// In particular, if the range has a single element, the second half is [`None`].
+    ///
+    /// This is intended for one-dimensional types.
+    /// For composite types, the halves are split by position, like with [`Ix::index`],
+    /// so their bounds generally describe boxes that overlap or don't cover the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Bounds;
+    /// assert_eq!(Bounds::new(0u8, 9).bisect(), (Bounds::new(0, 4), Some(Bounds::new(5, 9))));
+    /// assert_eq!(Bounds::new(0u8, 4).bisect(), (Bounds::new(0, 2), Some(Bounds::new(3, 4))));
+    /// assert_eq!(Bounds::new(7u8, 7).bisect(), (Bounds::new(7, 7), None));
+    /// ```
+    pub fn bisect(&self) -> (Bounds<I>, Option<Bounds<I>>) {
+        let (min, max) = (self.min.clone(), self.max.clone());
+        let size = Ix::range_size(min.clone(), max.clone());
+        let last = Ix::from_index((size - 1) / 2, min.clone(), max.clone());
+        let second = last
+            .clone()
+            .successor(min.clone(), max.clone())
+            .map(|first| Bounds { min: first, max });
+        (Bounds { min, max: last }, second)
+    }
+}
+
+impl<I: Ix + TryFrom<usize>> Bounds<I> {
+    /// Create the bounds of the range of the first `n` values starting from zero,
+    /// which is the range starting from `0` and stopping at `n - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n - 1` is not representable as a value of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Bounds, Ix};
+    /// let bounds = Bounds::<u8>::zero_to(5);
+    /// assert!(Ix::range_in(&bounds).eq([0, 1, 2, 3, 4]));
+    /// assert_eq!(Ix::range_size(*bounds.min(), *bounds.max()), 5);
+    /// ```
+    pub fn zero_to(n: usize) -> Self {
+        if n == 0 {
+            panic!("range is empty");
+        }
+        let convert = |n: usize| {
+            I::try_from(n)
+                .ok()
+                .expect("size is not representable by the type")
+        };
+        Bounds::new(convert(0), convert(n - 1))
+    }
+}
+
+impl<I> Bounds<I> {
+    /// Get the least element of the range.
+    pub fn min(&self) -> &I {
+        &self.min
+    }
+    /// Get the greatest element of the range.
+    pub fn max(&self) -> &I {
+        &self.max
+    }
+    /// Get the least and the greatest element of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Bounds;
+    /// assert_eq!(Bounds::new(-3i32, 3).into_parts(), (-3, 3));
+    /// ```
+    pub fn into_parts(self) -> (I, I) {
+        (self.min, self.max)
+    }
+}
+
+impl<I: Ix> TryFrom<(I, I)> for Bounds<I> {
+    type Error = RangeError;
+    fn try_from((min, max): (I, I)) -> Result<Self, Self::Error> {
+        Bounds::try_new(min, max)
+    }
+}
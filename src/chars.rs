@@ -0,0 +1,167 @@
+use crate::Ix;
+use core::ops::RangeInclusive;
+
+/// The number of surrogate code points, which are not valid [`char`] values.
+const SURROGATES: u32 = 0x800;
+
+/// Get the position of a [`char`] among all [`char`] values.
+fn to_scalar_index(c: char) -> u32 {
+    let c = c as u32;
+    if c >= 0xE000 {
+        c - SURROGATES
+    } else {
+        c
+    }
+}
+
+/// Get the [`char`] at a position among all [`char`] values.
+fn from_scalar_index(i: u32) -> Option<char> {
+    if i >= 0xD800 {
+        char::from_u32(i.checked_add(SURROGATES)?)
+    } else {
+        char::from_u32(i)
+    }
+}
+
+impl crate::Ix for char {
+    type Range = RangeInclusive<char>;
+    fn range(min: Self, max: Self) -> Self::Range {
+        assert_ordered!(min, max);
+        min..=max
+    }
+    fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+        assert_ordered!(min, max);
+        assert_in_range!(min, max, self);
+        usize::try_from(to_scalar_index(self) - to_scalar_index(min)).ok()
+    }
+    fn in_range(self, min: Self, max: Self) -> bool {
+        assert_ordered!(min, max);
+        min <= self && self <= max
+    }
+    fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+        assert_ordered!(min, max);
+        usize::try_from(to_scalar_index(max) - to_scalar_index(min))
+            .ok()
+            .and_then(|n| n.checked_add(1))
+    }
+    fn extent_u128(min: Self, max: Self) -> u128 {
+        assert_ordered!(min, max);
+        u128::from(to_scalar_index(max) - to_scalar_index(min))
+    }
+    fn index_u128(self, min: Self, max: Self) -> u128 {
+        assert_ordered!(min, max);
+        assert_in_range!(min, max, self);
+        u128::from(to_scalar_index(self) - to_scalar_index(min))
+    }
+    fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+        assert_ordered!(min, max);
+        let index = u32::try_from(index).ok()?;
+        let c = from_scalar_index(to_scalar_index(min).checked_add(index)?)?;
+        if c > max {
+            None
+        } else {
+            Some(c)
+        }
+    }
+    fn successor(self, min: Self, max: Self) -> Option<Self> {
+        assert_ordered!(min, max);
+        assert_in_range!(min, max, self);
+        if self == max {
+            None
+        } else {
+            from_scalar_index(to_scalar_index(self) + 1)
+        }
+    }
+    fn predecessor(self, min: Self, max: Self) -> Option<Self> {
+        assert_ordered!(min, max);
+        assert_in_range!(min, max, self);
+        if self == min {
+            None
+        } else {
+            from_scalar_index(to_scalar_index(self) - 1)
+        }
+    }
+}
+
+impl crate::BoundedIx for char {
+    const MIN: Self = '\0';
+    const MAX: Self = char::MAX;
+    fn pad_bounds(min: Self, max: Self, n: usize) -> (Self, Self) {
+        assert_ordered!(min, max);
+        let n = u32::try_from(n).unwrap_or(u32::MAX);
+        let min = from_scalar_index(to_scalar_index(min).saturating_sub(n))
+            .unwrap_or(<char as crate::BoundedIx>::MIN);
+        let max = to_scalar_index(max)
+            .checked_add(n)
+            .and_then(from_scalar_index)
+            .unwrap_or(<char as crate::BoundedIx>::MAX);
+        (min, max)
+    }
+    fn shrink_bounds(min: Self, max: Self, n: usize) -> Option<(Self, Self)> {
+        assert_ordered!(min, max);
+        let (min, max) = (to_scalar_index(min), to_scalar_index(max));
+        let n = u32::try_from(n).ok()?;
+        if n > (max - min) / 2 {
+            return None;
+        }
+        Some((from_scalar_index(min + n)?, from_scalar_index(max - n)?))
+    }
+}
+
+/// A trait for [`char`] providing positions of characters inside a range
+/// with and without the gap of surrogate code points.
+pub trait CharIx: Ix {
+    /// Get the position of a character inside a range,
+    /// skipping the surrogate code points, which are not valid [`char`] values.
+    /// This is the same as [`index`], and it satisfies the properties required by [`Ix`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    ///
+    /// Panics if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::CharIx;
+    /// assert_eq!('\u{E000}'.index_scalar('\u{D7FF}', char::MAX), 1);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    fn index_scalar(self, min: Self, max: Self) -> u32;
+    /// Get the difference between the code point of a character and the code point of `min`,
+    /// counting the surrogate code points in between.
+    /// Unlike [`index_scalar`], this does not satisfy the properties required by [`Ix`]
+    /// for ranges that contain the surrogate code points,
+    /// since it is not a position among the valid [`char`] values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    ///
+    /// Panics if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::CharIx;
+    /// assert_eq!('\u{E000}'.index_codepoint('\u{D7FF}', char::MAX), 0x801);
+    /// ```
+    ///
+    /// [`index_scalar`]: CharIx::index_scalar
+    fn index_codepoint(self, min: Self, max: Self) -> u32;
+}
+
+impl CharIx for char {
+    fn index_scalar(self, min: Self, max: Self) -> u32 {
+        assert_ordered!(min, max);
+        assert_in_range!(min, max, self);
+        to_scalar_index(self) - to_scalar_index(min)
+    }
+    fn index_codepoint(self, min: Self, max: Self) -> u32 {
+        assert_ordered!(min, max);
+        assert_in_range!(min, max, self);
+        self as u32 - min as u32
+    }
+}
@@ -0,0 +1,105 @@
+use crate::Ix;
+
+/// An iterator over the bounds of overlapping chunks of a range.
+///
+/// This struct is created by [`Ix::overlapping_chunks`].
+#[derive(Clone, Debug)]
+pub struct OverlappingChunks<I> {
+    next: Option<I>,
+    size: usize,
+    step: usize,
+    min: I,
+    max: I,
+}
+
+impl<I: Ix + Clone> OverlappingChunks<I> {
+    pub(crate) fn new(min: I, max: I, size: usize, overlap: usize) -> Self {
+        assert_ordered!(min, max);
+        if overlap >= size {
+            panic!("overlap is not less than chunk size");
+        }
+        OverlappingChunks {
+            next: Some(min.clone()),
+            size,
+            step: size - overlap,
+            min,
+            max,
+        }
+    }
+}
+
+impl<I: Ix + Clone> Iterator for OverlappingChunks<I> {
+    type Item = (I, I);
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.next.take()?;
+        let (min, max) = (&self.min, &self.max);
+        let last = first
+            .clone()
+            .advance(self.size - 1, min.clone(), max.clone())
+            .unwrap_or_else(|| max.clone());
+        if last != *max {
+            self.next = first.clone().advance(self.step, min.clone(), max.clone());
+        }
+        Some((first, last))
+    }
+}
+
+/// An iterator over the bounds of the chunks of a range that have exactly a given size.
+///
+/// This struct is created by [`Ix::chunks_exact`].
+#[derive(Clone, Debug)]
+pub struct ChunksExact<I> {
+    next: Option<I>,
+    remaining: usize,
+    size: usize,
+    min: I,
+    max: I,
+}
+
+impl<I: Ix + Clone> ChunksExact<I> {
+    pub(crate) fn new(min: I, max: I, size: usize) -> (Self, Option<(I, I)>) {
+        if size == 0 {
+            panic!("chunk size is zero");
+        }
+        let len = Ix::range_size(min.clone(), max.clone());
+        let chunks = len / size;
+        let remainder = if len.is_multiple_of(size) {
+            None
+        } else {
+            let first = Ix::from_index(chunks * size, min.clone(), max.clone());
+            Some((first, max.clone()))
+        };
+        let iter = ChunksExact {
+            next: Some(min.clone()),
+            remaining: chunks,
+            size,
+            min,
+            max,
+        };
+        (iter, remainder)
+    }
+}
+
+impl<I: Ix + Clone> Iterator for ChunksExact<I> {
+    type Item = (I, I);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let first = self.next.take()?;
+        let (min, max) = (&self.min, &self.max);
+        let last = first
+            .clone()
+            .advance(self.size - 1, min.clone(), max.clone())?;
+        if self.remaining > 0 {
+            self.next = last.clone().successor(min.clone(), max.clone());
+        }
+        Some((first, last))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<I: Ix + Clone> ExactSizeIterator for ChunksExact<I> {}
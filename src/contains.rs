@@ -0,0 +1,9 @@
+/// A query for [`Ix::contains`](crate::Ix::contains): either a single value or a whole range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Contains<I> {
+    /// A single value, which is contained in a range if it is in the range.
+    Point(I),
+    /// A range given by its least and greatest element,
+    /// which is contained in another range if both of its endpoints are.
+    Range(I, I),
+}
@@ -0,0 +1,40 @@
+use crate::Ix;
+
+/// An iterator over pairs of elements of a range taken from both ends, moving towards the middle.
+///
+/// This struct is created by [`Ix::converging_pairs`].
+#[derive(Clone, Debug)]
+pub struct ConvergingPairs<I> {
+    next: Option<(I, I)>,
+    min: I,
+    max: I,
+}
+
+impl<I: Ix + Clone> ConvergingPairs<I> {
+    pub(crate) fn new(min: I, max: I) -> Self {
+        assert_ordered!(min, max);
+        ConvergingPairs {
+            next: Some((min.clone(), max.clone())),
+            min,
+            max,
+        }
+    }
+}
+
+impl<I: Ix + Clone> Iterator for ConvergingPairs<I> {
+    type Item = (I, I);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (front, back) = self.next.take()?;
+        if front != back {
+            let (min, max) = (&self.min, &self.max);
+            let following = front.clone().successor(min.clone(), max.clone());
+            if following.as_ref() != Some(&back) {
+                let preceding = back.clone().predecessor(min.clone(), max.clone());
+                self.next = following.zip(preceding);
+            }
+        }
+        Some((front, back))
+    }
+}
+
+impl<I: Ix + Clone> core::iter::FusedIterator for ConvergingPairs<I> {}
@@ -0,0 +1,34 @@
+use crate::Ix;
+
+/// An iterator over a range that keeps track of how many elements remain.
+///
+/// This struct is created by [`Ix::counted_range`].
+#[derive(Clone, Debug)]
+pub struct CountedRange<I: Ix> {
+    inner: I::Range,
+    remaining: usize,
+}
+
+impl<I: Ix> CountedRange<I> {
+    pub(crate) fn new(inner: I::Range, remaining: usize) -> Self {
+        CountedRange { inner, remaining }
+    }
+    /// Get the number of elements that have not been produced yet.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<I: Ix> Iterator for CountedRange<I> {
+    type Item = I;
+    fn next(&mut self) -> Option<Self::Item> {
+        let ix = self.inner.next()?;
+        self.remaining -= 1;
+        Some(ix)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<I: Ix> ExactSizeIterator for CountedRange<I> {}
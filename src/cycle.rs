@@ -0,0 +1,39 @@
+use crate::Ix;
+
+/// An iterator that repeats the elements of a range endlessly.
+///
+/// This struct is created by [`Ix::cycle_range`].
+#[derive(Clone, Debug)]
+pub struct CycleRange<I> {
+    next: I,
+    min: I,
+    max: I,
+}
+
+impl<I: Ix + Clone> CycleRange<I> {
+    pub(crate) fn new(min: I, max: I) -> Self {
+        assert_ordered!(min, max);
+        CycleRange {
+            next: min.clone(),
+            min,
+            max,
+        }
+    }
+}
+
+impl<I: Ix + Clone> Iterator for CycleRange<I> {
+    type Item = I;
+    fn next(&mut self) -> Option<Self::Item> {
+        let following = self
+            .next
+            .clone()
+            .successor(self.min.clone(), self.max.clone())
+            .unwrap_or_else(|| self.min.clone());
+        Some(core::mem::replace(&mut self.next, following))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I: Ix + Clone> core::iter::FusedIterator for CycleRange<I> {}
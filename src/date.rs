@@ -0,0 +1,107 @@
+use core::{iter::Map, ops::RangeInclusive};
+use time::Date;
+
+/// Get the date with a Julian day number known to be valid.
+fn date(day: i32) -> Date {
+    Date::from_julian_day(day).expect("Julian day is out of range")
+}
+
+/// Dates are indexed by their Julian day numbers, so consecutive dates are consecutive days.
+impl crate::Ix for Date {
+    type Range = Map<RangeInclusive<i32>, fn(i32) -> Date>;
+    fn range(min: Self, max: Self) -> Self::Range {
+        <i32 as crate::Ix>::range(min.to_julian_day(), max.to_julian_day())
+            .map(date as fn(i32) -> Date)
+    }
+    fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+        <i32 as crate::Ix>::index_checked(
+            self.to_julian_day(),
+            min.to_julian_day(),
+            max.to_julian_day(),
+        )
+    }
+    fn in_range(self, min: Self, max: Self) -> bool {
+        <i32 as crate::Ix>::in_range(
+            self.to_julian_day(),
+            min.to_julian_day(),
+            max.to_julian_day(),
+        )
+    }
+    fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+        <i32 as crate::Ix>::range_size_checked(min.to_julian_day(), max.to_julian_day())
+    }
+    fn extent_u128(min: Self, max: Self) -> u128 {
+        <i32 as crate::Ix>::extent_u128(min.to_julian_day(), max.to_julian_day())
+    }
+    fn index_u128(self, min: Self, max: Self) -> u128 {
+        <i32 as crate::Ix>::index_u128(
+            self.to_julian_day(),
+            min.to_julian_day(),
+            max.to_julian_day(),
+        )
+    }
+    fn manhattan_distance(self, other: Self, min: Self, max: Self) -> u128 {
+        <i32 as crate::Ix>::manhattan_distance(
+            self.to_julian_day(),
+            other.to_julian_day(),
+            min.to_julian_day(),
+            max.to_julian_day(),
+        )
+    }
+    fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+        <i32 as crate::Ix>::from_index_checked(index, min.to_julian_day(), max.to_julian_day())
+            .map(date)
+    }
+    fn successor(self, min: Self, max: Self) -> Option<Self> {
+        <i32 as crate::Ix>::successor(
+            self.to_julian_day(),
+            min.to_julian_day(),
+            max.to_julian_day(),
+        )
+        .map(date)
+    }
+    fn predecessor(self, min: Self, max: Self) -> Option<Self> {
+        <i32 as crate::Ix>::predecessor(
+            self.to_julian_day(),
+            min.to_julian_day(),
+            max.to_julian_day(),
+        )
+        .map(date)
+    }
+    fn advance(self, n: usize, min: Self, max: Self) -> Option<Self> {
+        <i32 as crate::Ix>::advance(
+            self.to_julian_day(),
+            n,
+            min.to_julian_day(),
+            max.to_julian_day(),
+        )
+        .map(date)
+    }
+    fn retreat(self, n: usize, min: Self, max: Self) -> Option<Self> {
+        <i32 as crate::Ix>::retreat(
+            self.to_julian_day(),
+            n,
+            min.to_julian_day(),
+            max.to_julian_day(),
+        )
+        .map(date)
+    }
+}
+
+impl crate::BoundedIx for Date {
+    const MIN: Self = Date::MIN;
+    const MAX: Self = Date::MAX;
+    fn pad_bounds(min: Self, max: Self, n: usize) -> (Self, Self) {
+        let (lo, hi) =
+            <i32 as crate::BoundedIx>::pad_bounds(min.to_julian_day(), max.to_julian_day(), n);
+        (
+            date(lo.max(Date::MIN.to_julian_day())),
+            date(hi.min(Date::MAX.to_julian_day())),
+        )
+    }
+    fn shrink_bounds(min: Self, max: Self, n: usize) -> Option<(Self, Self)> {
+        let (lo, hi) =
+            <i32 as crate::BoundedIx>::shrink_bounds(min.to_julian_day(), max.to_julian_day(), n)?;
+        Some((date(lo), date(hi)))
+    }
+}
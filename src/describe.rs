@@ -0,0 +1,31 @@
+use crate::Ix;
+use core::fmt;
+
+/// A description of a range, listing its endpoints and its size.
+///
+/// This struct is created by [`Ix::describe`].
+/// Its [`Display`](fmt::Display) implementation formats the range like `[-5..=10] (16 elements)`,
+/// with the endpoints formatted with [`Debug`](fmt::Debug).
+#[derive(Clone, Debug)]
+pub struct Describe<I> {
+    min: I,
+    max: I,
+}
+
+impl<I: Ix> Describe<I> {
+    pub(crate) fn new(min: I, max: I) -> Self {
+        assert_ordered!(min, max);
+        Describe { min, max }
+    }
+}
+
+impl<I: Ix + Clone + fmt::Debug> fmt::Display for Describe<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:?}..={:?}] ", self.min, self.max)?;
+        match Ix::range_size_checked(self.min.clone(), self.max.clone()) {
+            Some(1) => f.write_str("(1 element)"),
+            Some(size) => write!(f, "({} elements)", size),
+            None => f.write_str("(overflows usize)"),
+        }
+    }
+}
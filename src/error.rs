@@ -0,0 +1,76 @@
+use core::fmt;
+
+/// An error indicating that the length of a slice differs from the size of a range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthMismatch {
+    /// The size of the range, or [`None`] if it is not representable as a [`usize`] value.
+    pub expected: Option<usize>,
+    /// The length of the slice.
+    pub actual: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.expected {
+            Some(expected) => write!(
+                f,
+                "slice length {} differs from range size {}",
+                self.actual, expected
+            ),
+            None => write!(
+                f,
+                "slice length {} differs from range size (too large)",
+                self.actual
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthMismatch {}
+
+/// An error indicating that the least element of a range is greater than its greatest element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RangeError;
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("min is greater than max")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
+
+/// An error indicating that a value is not inside a range.
+///
+/// This enum is returned by [`Ix::check_in_range`](crate::Ix::check_in_range).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OutOfRange<I> {
+    /// The value is less than the least element of the range.
+    BelowMin(I),
+    /// The value is not less than the least element of the range,
+    /// but it is not inside the range either.
+    AboveMax(I),
+}
+
+impl<I> OutOfRange<I> {
+    /// Get the value that is not inside the range.
+    pub fn into_inner(self) -> I {
+        match self {
+            OutOfRange::BelowMin(ix) | OutOfRange::AboveMax(ix) => ix,
+        }
+    }
+}
+
+impl<I> fmt::Display for OutOfRange<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutOfRange::BelowMin(_) => f.write_str("index is outside range (< min)"),
+            OutOfRange::AboveMax(_) => f.write_str("index is outside range (> max)"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: fmt::Debug> std::error::Error for OutOfRange<I> {}
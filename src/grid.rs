@@ -0,0 +1,774 @@
+use crate::{Bounds, Ix};
+
+/// A trait for two-dimensional indices.
+///
+/// This trait is implemented for pairs of [`Ix`] values.
+/// The first component of a pair is its row and the second component is its column,
+/// matching the row-major order in which the [`Ix`] implementation for pairs enumerates a range.
+pub trait Ix2: Ix {
+    /// Generate an iterator over the diagonal of a range starting from `min` and stopping at `max`.
+    /// The diagonal starts at `min` and advances the row and the column in lockstep,
+    /// stopping after either of them has reached its greatest value.
+    /// For a range that isn't square, the diagonal therefore stops at the shorter axis.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert!(Ix2::diagonal((0u8, 0u8), (2, 2)).eq([(0, 0), (1, 1), (2, 2)]));
+    /// assert!(Ix2::diagonal((0u8, 3u8), (4, 4)).eq([(0, 3), (1, 4)]));
+    /// ```
+    fn diagonal(min: Self, max: Self) -> Diagonal<Self>;
+    /// Generate an iterator over the boundary of a range starting from `min` and stopping at `max`.
+    /// The boundary consists of the first and last row and the first and last column.
+    /// It is traversed clockwise, starting from `min`, and every element is produced exactly once.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert!(Ix2::boundary((0u8, 0u8), (2, 2)).eq([
+    ///     (0, 0),
+    ///     (0, 1),
+    ///     (0, 2),
+    ///     (1, 2),
+    ///     (2, 2),
+    ///     (2, 1),
+    ///     (2, 0),
+    ///     (1, 0),
+    /// ]));
+    /// ```
+    fn boundary(min: Self, max: Self) -> Boundary<Self>;
+    /// Generate an iterator over the orthogonal neighbors of a value inside a range.
+    /// Neighbors outside the range are omitted.
+    /// The neighbors are produced clockwise, starting from the one in the previous row.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert!((1u8, 1u8).neighbors4((0, 0), (2, 2)).eq([(0, 1), (1, 2), (2, 1), (1, 0)]));
+    /// assert!((0u8, 0u8).neighbors4((0, 0), (2, 2)).eq([(0, 1), (1, 0)]));
+    /// ```
+    ///
+    /// [`in_range`]: Ix::in_range
+    fn neighbors4(self, min: Self, max: Self) -> Neighbors<Self>;
+    /// Generate an iterator over the orthogonal and diagonal neighbors of a value inside a range.
+    /// Neighbors outside the range are omitted.
+    /// The neighbors are produced clockwise, starting from the one in the previous row.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert_eq!((1u8, 1u8).neighbors8((0, 0), (2, 2)).count(), 8);
+    /// assert!((0u8, 0u8).neighbors8((0, 0), (2, 2)).eq([(0, 1), (1, 1), (1, 0)]));
+    /// ```
+    ///
+    /// [`in_range`]: Ix::in_range
+    fn neighbors8(self, min: Self, max: Self) -> Neighbors<Self>;
+    /// Generate an iterator over a range starting from `min` and stopping at `max`, in snake order.
+    /// Every row is traversed in turn, like with [`range`],
+    /// but every other row is traversed in reverse, starting from its last column.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert!(Ix2::snake_range((0u8, 0u8), (2, 1)).eq([(0, 0), (0, 1), (1, 1), (1, 0), (2, 0), (2, 1)]));
+    /// ```
+    ///
+    /// [`range`]: Ix::range
+    fn snake_range(min: Self, max: Self) -> Snake<Self>;
+    /// Generate an iterator over the tiles of a range starting from `min` and stopping at `max`.
+    /// Every tile spans at most `tile.0` rows and `tile.1` columns.
+    /// The tiles are produced in row-major order and partition the range,
+    /// with the tiles along the last row and column clipped to the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either component of `tile` is zero.
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Bounds, Ix2};
+    /// assert!(Ix2::tiles((0u8, 0u8), (2, 4), (2, 3)).eq([
+    ///     Bounds::new((0, 0), (1, 2)),
+    ///     Bounds::new((0, 3), (1, 4)),
+    ///     Bounds::new((2, 0), (2, 2)),
+    ///     Bounds::new((2, 3), (2, 4)),
+    /// ]));
+    /// ```
+    fn tiles(min: Self, max: Self, tile: (usize, usize)) -> Tiles<Self>;
+    /// Generate an iterator over a range starting from `min` and stopping at `max`, in row-major order,
+    /// for staggered layouts such as hexagonal grids and brick walls, where every other row is offset.
+    /// Every element is produced along with whether its row is offset,
+    /// which is the case for the second row, the fourth row, and so on.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert!(Ix2::staggered_range((0u8, 0u8), (1, 1)).eq([
+    ///     (0, 0, false),
+    ///     (0, 1, false),
+    ///     (1, 0, true),
+    ///     (1, 1, true),
+    /// ]));
+    /// ```
+    fn staggered_range(min: Self, max: Self) -> Staggered<Self>;
+    /// Get the Morton code (Z-order code) of a value in a range starting from `min` and stopping at `max`.
+    /// The code interleaves the bits of the positions of the row and the column,
+    /// with the bits of the row being the more significant of each pair.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if any component of `self` is not in the corresponding range.
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert_eq!((0u8, 0u8).morton_index((0, 0), (3, 3)), 0);
+    /// assert_eq!((1u8, 0u8).morton_index((0, 0), (3, 3)), 2);
+    /// assert_eq!((2u8, 3u8).morton_index((0, 0), (3, 3)), 13);
+    /// ```
+    fn morton_index(self, min: Self, max: Self) -> u128;
+    /// Generate an iterator over a range starting from `min` and stopping at `max`, in Morton order (Z-order).
+    /// The elements are produced in increasing order of their [`morton_index`].
+    /// If the range isn't a square with a power of two as its side length,
+    /// the codes that fall outside of it are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// Panics if the number of rows or the number of columns is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert!(Ix2::morton_range((0u8, 0u8), (2, 1)).eq([(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1)]));
+    /// assert!(Ix2::morton_range((0u8, 0u8), (1, 2)).eq([(0, 0), (0, 1), (1, 0), (1, 1), (0, 2), (1, 2)]));
+    /// ```
+    ///
+    /// [`morton_index`]: Ix2::morton_index
+    fn morton_range(min: Self, max: Self) -> Morton<Self>;
+    /// Get the position of a value along the Hilbert curve through a range starting from `min` and stopping at `max`.
+    /// The range must be a square whose side length is a power of two.
+    /// The curve starts at `min` and ends at the last column of the first row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is not a square whose side length is a power of two.
+    ///
+    /// Should panic if any component of `self` is not in the corresponding range.
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert_eq!((0u8, 0u8).hilbert_index((0, 0), (1, 1)), 0);
+    /// assert_eq!((1u8, 0u8).hilbert_index((0, 0), (1, 1)), 1);
+    /// assert_eq!((1u8, 1u8).hilbert_index((0, 0), (1, 1)), 2);
+    /// assert_eq!((0u8, 1u8).hilbert_index((0, 0), (1, 1)), 3);
+    /// ```
+    fn hilbert_index(self, min: Self, max: Self) -> u128;
+    /// Get the value at a position along the Hilbert curve through a range starting from `min` and stopping at `max`,
+    /// undoing [`hilbert_index`].
+    /// Returns [`None`] if the position is not less than the size of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is not a square whose side length is a power of two.
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert_eq!(Ix2::from_hilbert_index(2, (0u8, 0u8), (1, 1)), Some((1, 1)));
+    /// assert_eq!(Ix2::from_hilbert_index(4, (0u8, 0u8), (1, 1)), None);
+    /// ```
+    ///
+    /// [`hilbert_index`]: Ix2::hilbert_index
+    fn from_hilbert_index(index: u128, min: Self, max: Self) -> Option<Self>;
+    /// Generate an iterator over a range starting from `min` and stopping at `max`, along a Hilbert curve.
+    /// The range must be a square whose side length is a power of two.
+    /// The elements are produced in increasing order of their [`hilbert_index`],
+    /// so consecutive elements are always adjacent within a row or a column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is not a square whose side length is a power of two.
+    ///
+    /// Should panic if any component of `min` is greater than the corresponding component of `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix2;
+    /// assert!(Ix2::hilbert_range((0u8, 0u8), (1, 1)).eq([(0, 0), (1, 0), (1, 1), (0, 1)]));
+    /// ```
+    ///
+    /// [`hilbert_index`]: Ix2::hilbert_index
+    fn hilbert_range(min: Self, max: Self) -> Hilbert<Self>;
+}
+
+impl<A: Ix + Clone, B: Ix + Clone> Ix2 for (A, B) {
+    fn diagonal(min: Self, max: Self) -> Diagonal<Self> {
+        assert_ordered!(min.0, max.0);
+        assert_ordered!(min.1, max.1);
+        Diagonal {
+            next: Some(min.clone()),
+            min,
+            max,
+        }
+    }
+    fn boundary(min: Self, max: Self) -> Boundary<Self> {
+        assert_ordered!(min.0, max.0);
+        assert_ordered!(min.1, max.1);
+        Boundary {
+            next: Some(min.clone()),
+            side: Side::Top,
+            min,
+            max,
+        }
+    }
+    fn neighbors4(self, min: Self, max: Self) -> Neighbors<Self> {
+        Neighbors::new(self, min, max, &NEIGHBORS4)
+    }
+    fn neighbors8(self, min: Self, max: Self) -> Neighbors<Self> {
+        Neighbors::new(self, min, max, &NEIGHBORS8)
+    }
+    fn snake_range(min: Self, max: Self) -> Snake<Self> {
+        assert_ordered!(min.0, max.0);
+        assert_ordered!(min.1, max.1);
+        Snake {
+            next: Some(min.clone()),
+            reversed: false,
+            min,
+            max,
+        }
+    }
+    fn tiles(min: Self, max: Self, tile: (usize, usize)) -> Tiles<Self> {
+        assert_ordered!(min.0, max.0);
+        assert_ordered!(min.1, max.1);
+        if tile.0 == 0 || tile.1 == 0 {
+            panic!("tile size is zero");
+        }
+        Tiles {
+            next: Some(min.clone()),
+            tile,
+            min,
+            max,
+        }
+    }
+    fn staggered_range(min: Self, max: Self) -> Staggered<Self> {
+        assert_ordered!(min.0, max.0);
+        assert_ordered!(min.1, max.1);
+        Staggered {
+            next: Some(min.clone()),
+            offset: false,
+            min,
+            max,
+        }
+    }
+    fn morton_index(self, min: Self, max: Self) -> u128 {
+        let row = self.0.index(min.0, max.0);
+        let col = self.1.index(min.1, max.1);
+        interleave(row, col)
+    }
+    fn morton_range(min: Self, max: Self) -> Morton<Self> {
+        let rows = Ix::range_size(min.0.clone(), max.0.clone());
+        let cols = Ix::range_size(min.1.clone(), max.1.clone());
+        Morton {
+            next: Some(0),
+            last: interleave(rows - 1, cols - 1),
+            rows,
+            cols,
+            min,
+            max,
+        }
+    }
+    fn hilbert_index(self, min: Self, max: Self) -> u128 {
+        let side = hilbert_side(&min, &max);
+        let row = self.0.index(min.0, max.0) as u128;
+        let col = self.1.index(min.1, max.1) as u128;
+        hilbert_encode(side, row, col)
+    }
+    fn from_hilbert_index(index: u128, min: Self, max: Self) -> Option<Self> {
+        let side = hilbert_side(&min, &max);
+        if index >= side * side {
+            return None;
+        }
+        let (row, col) = hilbert_decode(side, index);
+        Some((
+            Ix::from_index(row as usize, min.0.clone(), max.0),
+            Ix::from_index(col as usize, min.1.clone(), max.1),
+        ))
+    }
+    fn hilbert_range(min: Self, max: Self) -> Hilbert<Self> {
+        let side = hilbert_side(&min, &max);
+        Hilbert {
+            next: Some(0),
+            last: side * side - 1,
+            side,
+            min,
+            max,
+        }
+    }
+}
+
+/// An iterator over the diagonal of a two-dimensional range.
+///
+/// This struct is created by [`Ix2::diagonal`].
+#[derive(Clone, Debug)]
+pub struct Diagonal<T> {
+    next: Option<T>,
+    min: T,
+    max: T,
+}
+
+impl<A: Ix + Clone, B: Ix + Clone> Iterator for Diagonal<(A, B)> {
+    type Item = (A, B);
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        self.next = current
+            .0
+            .clone()
+            .successor(self.min.0.clone(), self.max.0.clone())
+            .zip(
+                current
+                    .1
+                    .clone()
+                    .successor(self.min.1.clone(), self.max.1.clone()),
+            );
+        Some(current)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+/// An iterator over the boundary of a two-dimensional range.
+///
+/// This struct is created by [`Ix2::boundary`].
+#[derive(Clone, Debug)]
+pub struct Boundary<T> {
+    next: Option<T>,
+    side: Side,
+    min: T,
+    max: T,
+}
+
+impl<A: Ix + Clone, B: Ix + Clone> Iterator for Boundary<(A, B)> {
+    type Item = (A, B);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (row, col) = self.next.take()?;
+        let (min, max) = (&self.min, &self.max);
+        let next_col = || col.clone().successor(min.1.clone(), max.1.clone());
+        let next_row = || row.clone().successor(min.0.clone(), max.0.clone());
+        let previous_col = || col.clone().predecessor(min.1.clone(), max.1.clone());
+        let previous_row = || {
+            row.clone()
+                .predecessor(min.0.clone(), max.0.clone())
+                .filter(|row| *row != min.0)
+        };
+        self.next = match self.side {
+            Side::Top => match next_col() {
+                Some(col) => Some((row.clone(), col)),
+                None => {
+                    self.side = Side::Right;
+                    next_row().map(|row| (row, col.clone()))
+                }
+            },
+            Side::Right => match next_row() {
+                Some(row) => Some((row, col.clone())),
+                None => {
+                    self.side = Side::Bottom;
+                    previous_col().map(|col| (row.clone(), col))
+                }
+            },
+            Side::Bottom => match previous_col() {
+                Some(col) => Some((row.clone(), col)),
+                None => {
+                    self.side = Side::Left;
+                    previous_row().map(|row| (row, col.clone()))
+                }
+            },
+            Side::Left => previous_row().map(|row| (row, col.clone())),
+        };
+        Some((row, col))
+    }
+}
+
+const NEIGHBORS4: [(i8, i8); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+const NEIGHBORS8: [(i8, i8); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// Step a value by at most one position inside a range.
+fn step<I: Ix + Clone>(ix: &I, min: &I, max: &I, offset: i8) -> Option<I> {
+    match offset {
+        -1 => ix.clone().predecessor(min.clone(), max.clone()),
+        0 => Some(ix.clone()),
+        _ => ix.clone().successor(min.clone(), max.clone()),
+    }
+}
+
+/// An iterator over the neighbors of a value inside a two-dimensional range.
+///
+/// This struct is created by [`Ix2::neighbors4`] and [`Ix2::neighbors8`].
+#[derive(Clone, Debug)]
+pub struct Neighbors<T> {
+    center: T,
+    min: T,
+    max: T,
+    offsets: core::slice::Iter<'static, (i8, i8)>,
+}
+
+impl<A: Ix + Clone, B: Ix + Clone> Neighbors<(A, B)> {
+    fn new(center: (A, B), min: (A, B), max: (A, B), offsets: &'static [(i8, i8)]) -> Self {
+        assert_ordered!(min.0, max.0);
+        assert_ordered!(min.1, max.1);
+        assert_in_range!(min.0, max.0, center.0);
+        assert_in_range!(min.1, max.1, center.1);
+        Neighbors {
+            center,
+            min,
+            max,
+            offsets: offsets.iter(),
+        }
+    }
+}
+
+impl<A: Ix + Clone, B: Ix + Clone> Iterator for Neighbors<(A, B)> {
+    type Item = (A, B);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (center, min, max) = (&self.center, &self.min, &self.max);
+        self.offsets.find_map(|&(row, col)| {
+            Some((
+                step(&center.0, &min.0, &max.0, row)?,
+                step(&center.1, &min.1, &max.1, col)?,
+            ))
+        })
+    }
+}
+
+/// An iterator over a two-dimensional range in snake order.
+///
+/// This struct is created by [`Ix2::snake_range`].
+#[derive(Clone, Debug)]
+pub struct Snake<T> {
+    next: Option<T>,
+    reversed: bool,
+    min: T,
+    max: T,
+}
+
+impl<A: Ix + Clone, B: Ix + Clone> Iterator for Snake<(A, B)> {
+    type Item = (A, B);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (row, col) = self.next.take()?;
+        let (min, max) = (&self.min, &self.max);
+        let next_col = if self.reversed {
+            col.clone().predecessor(min.1.clone(), max.1.clone())
+        } else {
+            col.clone().successor(min.1.clone(), max.1.clone())
+        };
+        self.next = match next_col {
+            Some(next_col) => Some((row.clone(), next_col)),
+            None => {
+                self.reversed = !self.reversed;
+                row.clone()
+                    .successor(min.0.clone(), max.0.clone())
+                    .map(|next_row| (next_row, col.clone()))
+            }
+        };
+        Some((row, col))
+    }
+}
+
+/// An iterator over a two-dimensional range in row-major order,
+/// along with whether the row of each element is offset.
+///
+/// This struct is created by [`Ix2::staggered_range`].
+#[derive(Clone, Debug)]
+pub struct Staggered<T> {
+    next: Option<T>,
+    offset: bool,
+    min: T,
+    max: T,
+}
+
+impl<A: Ix + Clone, B: Ix + Clone> Iterator for Staggered<(A, B)> {
+    type Item = (A, B, bool);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (row, col) = self.next.take()?;
+        let offset = self.offset;
+        let (min, max) = (&self.min, &self.max);
+        self.next = match col.clone().successor(min.1.clone(), max.1.clone()) {
+            Some(next_col) => Some((row.clone(), next_col)),
+            None => {
+                self.offset = !self.offset;
+                row.clone()
+                    .successor(min.0.clone(), max.0.clone())
+                    .map(|next_row| (next_row, min.1.clone()))
+            }
+        };
+        Some((row, col, offset))
+    }
+}
+
+/// An iterator over the tiles of a two-dimensional range.
+///
+/// This struct is created by [`Ix2::tiles`].
+#[derive(Clone, Debug)]
+pub struct Tiles<T> {
+    next: Option<T>,
+    tile: (usize, usize),
+    min: T,
+    max: T,
+}
+
+impl<A: Ix + Clone, B: Ix + Clone> Iterator for Tiles<(A, B)> {
+    type Item = Bounds<(A, B)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (row, col) = self.next.take()?;
+        let (min, max) = (&self.min, &self.max);
+        let last_row = row
+            .clone()
+            .advance(self.tile.0 - 1, min.0.clone(), max.0.clone())
+            .unwrap_or_else(|| max.0.clone());
+        let last_col = col
+            .clone()
+            .advance(self.tile.1 - 1, min.1.clone(), max.1.clone())
+            .unwrap_or_else(|| max.1.clone());
+        self.next = match last_col.clone().successor(min.1.clone(), max.1.clone()) {
+            Some(next_col) => Some((row.clone(), next_col)),
+            None => last_row
+                .clone()
+                .successor(min.0.clone(), max.0.clone())
+                .map(|next_row| (next_row, min.1.clone())),
+        };
+        Some(Bounds::new((row, col), (last_row, last_col)))
+    }
+}
+
+/// Spread the bits of a position out to the even bits of a [`u128`] value.
+fn spread(x: usize) -> u128 {
+    let mut x = x as u128;
+    x = (x | x << 32) & (u128::MAX / 0x1_0000_0001);
+    x = (x | x << 16) & (u128::MAX / 0x1_0001);
+    x = (x | x << 8) & (u128::MAX / 0x101);
+    x = (x | x << 4) & (u128::MAX / 0x11);
+    x = (x | x << 2) & (u128::MAX / 0x5);
+    (x | x << 1) & (u128::MAX / 0x3)
+}
+
+/// Gather the even bits of a [`u128`] value, undoing [`spread`].
+fn gather(x: u128) -> u128 {
+    let mut x = x & (u128::MAX / 0x3);
+    x = (x | x >> 1) & (u128::MAX / 0x5);
+    x = (x | x >> 2) & (u128::MAX / 0x11);
+    x = (x | x >> 4) & (u128::MAX / 0x101);
+    x = (x | x >> 8) & (u128::MAX / 0x1_0001);
+    x = (x | x >> 16) & (u128::MAX / 0x1_0000_0001);
+    (x | x >> 32) & u128::from(u64::MAX)
+}
+
+/// Get the Morton code of a pair of positions.
+fn interleave(row: usize, col: usize) -> u128 {
+    spread(row) << 1 | spread(col)
+}
+
+/// Get the least Morton code greater than `code` whose positions are at most those of `last`,
+/// given that `code` is less than `last` and has a position greater than that of `last`.
+///
+/// This is the BIGMIN computation of Tropf and Herzog, for a box whose least code is zero.
+fn next_in_box(code: u128, last: u128) -> u128 {
+    let (mut min, mut max) = (0u128, last);
+    let mut next = 0;
+    for bit in (0..u128::BITS).rev() {
+        let below = (u128::MAX / 0x3) << (bit % 2) & ((1 << bit) - 1);
+        let (set, clear) = (1 << bit, !(1u128 << bit));
+        match (code & set != 0, min & set != 0, max & set != 0) {
+            (false, false, true) => {
+                next = (min & !below) | set;
+                max = (max & clear) | below;
+            }
+            (false, true, true) => return min,
+            (true, false, false) => return next,
+            (true, false, true) => min = (min & !below) | set,
+            _ => {}
+        }
+    }
+    next
+}
+
+/// An iterator over a two-dimensional range in Morton order (Z-order).
+///
+/// This struct is created by [`Ix2::morton_range`].
+#[derive(Clone, Debug)]
+pub struct Morton<T> {
+    next: Option<u128>,
+    last: u128,
+    rows: usize,
+    cols: usize,
+    min: T,
+    max: T,
+}
+
+impl<A: Ix + Clone, B: Ix + Clone> Iterator for Morton<(A, B)> {
+    type Item = (A, B);
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut code = self.next?;
+        let (mut row, mut col) = (gather(code >> 1), gather(code));
+        while row >= self.rows as u128 || col >= self.cols as u128 {
+            code = next_in_box(code, self.last);
+            (row, col) = (gather(code >> 1), gather(code));
+        }
+        self.next = if code == self.last {
+            None
+        } else {
+            Some(code + 1)
+        };
+        Some((
+            Ix::from_index(row as usize, self.min.0.clone(), self.max.0.clone()),
+            Ix::from_index(col as usize, self.min.1.clone(), self.max.1.clone()),
+        ))
+    }
+}
+
+/// Get the side length of a square range whose side length is a power of two.
+fn hilbert_side<A: Ix + Clone, B: Ix + Clone>(min: &(A, B), max: &(A, B)) -> u128 {
+    let rows = Ix::range_size(min.0.clone(), max.0.clone());
+    let cols = Ix::range_size(min.1.clone(), max.1.clone());
+    if rows != cols || !rows.is_power_of_two() {
+        panic!("range is not a square whose side length is a power of two");
+    }
+    rows as u128
+}
+
+/// Reflect and transpose a position within a square of the given side length,
+/// as a step of following the Hilbert curve.
+fn hilbert_rotate(side: u128, row: &mut u128, col: &mut u128, flip_row: bool, flip_col: bool) {
+    if !flip_row {
+        if flip_col {
+            *row = side - 1 - *row;
+            *col = side - 1 - *col;
+        }
+        core::mem::swap(row, col);
+    }
+}
+
+/// Get the position along the Hilbert curve of a pair of positions.
+fn hilbert_encode(side: u128, mut row: u128, mut col: u128) -> u128 {
+    let mut index = 0;
+    let mut s = side / 2;
+    while s > 0 {
+        let flip_col = col & s != 0;
+        let flip_row = row & s != 0;
+        index += s * s * ((3 * flip_col as u128) ^ flip_row as u128);
+        hilbert_rotate(side, &mut row, &mut col, flip_row, flip_col);
+        s /= 2;
+    }
+    index
+}
+
+/// Get the pair of positions at a position along the Hilbert curve, undoing [`hilbert_encode`].
+fn hilbert_decode(side: u128, index: u128) -> (u128, u128) {
+    let (mut row, mut col) = (0, 0);
+    let mut t = index;
+    let mut s = 1;
+    while s < side {
+        let flip_col = t & 2 != 0;
+        let flip_row = (t ^ flip_col as u128) & 1 != 0;
+        hilbert_rotate(s, &mut row, &mut col, flip_row, flip_col);
+        col += s * flip_col as u128;
+        row += s * flip_row as u128;
+        t /= 4;
+        s *= 2;
+    }
+    (row, col)
+}
+
+/// An iterator over a two-dimensional range along a Hilbert curve.
+///
+/// This struct is created by [`Ix2::hilbert_range`].
+#[derive(Clone, Debug)]
+pub struct Hilbert<T> {
+    next: Option<u128>,
+    last: u128,
+    side: u128,
+    min: T,
+    max: T,
+}
+
+impl<A: Ix + Clone, B: Ix + Clone> Iterator for Hilbert<(A, B)> {
+    type Item = (A, B);
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next?;
+        self.next = if index == self.last {
+            None
+        } else {
+            Some(index + 1)
+        };
+        let (row, col) = hilbert_decode(self.side, index);
+        Some((
+            Ix::from_index(row as usize, self.min.0.clone(), self.max.0.clone()),
+            Ix::from_index(col as usize, self.min.1.clone(), self.max.1.clone()),
+        ))
+    }
+}
@@ -1,5 +1,77 @@
 #![no_std]
 //! This crate provides a trait ([`Ix`]) for values that permit contiguous subranges.  
+//!
+//! Enabling the `alloc` feature provides methods that collect ranges into allocated collections.
+//!
+//! Enabling the `std` feature (which implies `alloc`) provides methods that print to standard output.
+//!
+//! Enabling the `testing` feature (which implies `std`) provides the `validate` function,
+//! which checks the invariants of an [`Ix`] implementation.
+//!
+//! Enabling the `num-bigint` feature provides implementations for the integer types of the `num-bigint` crate.
+//!
+//! Enabling the `time` feature provides an implementation for the `Date` type of the `time` crate.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt;
+
+macro_rules! assert_ordered {
+    ($min: expr, $max: expr) => {
+        if $min > $max {
+            panic!("min is greater than max");
+        }
+    };
+}
+
+macro_rules! assert_in_range {
+    ($min: expr, $max: expr, $ix: expr) => {
+        if $ix < $min {
+            panic!("index is outside range (< min)");
+        } else if $ix > $max {
+            panic!("index is outside range (> max)");
+        }
+    };
+}
+
+mod arrays;
+mod bins;
+mod bounds;
+mod chunks;
+mod contains;
+mod converging;
+mod counted;
+mod cycle;
+mod describe;
+mod error;
+mod grid;
+mod linspace;
+pub mod prelude;
+mod reverse;
+mod tuples;
+mod with_bounds;
+mod within;
+
+pub use arrays::ArrayRange;
+pub use bins::HistogramBins;
+pub use bounds::Bounds;
+pub use chunks::{ChunksExact, OverlappingChunks};
+pub use contains::Contains;
+pub use converging::ConvergingPairs;
+pub use counted::CountedRange;
+pub use cycle::CycleRange;
+pub use describe::Describe;
+pub use error::{LengthMismatch, OutOfRange, RangeError};
+pub use grid::{Boundary, Diagonal, Hilbert, Ix2, Morton, Neighbors, Snake, Staggered, Tiles};
+pub use linspace::Linspace;
+pub use reverse::ReverseRange;
+pub use tuples::TupleRange;
+pub use with_bounds::WithBounds;
+pub use within::RangeWithin;
 
 /// A trait for values that permit contiguous subranges.
 ///
@@ -16,6 +88,14 @@
 ///
 /// Note that, for these properties, if one side of the equality panics or overflows the equality can be considered to hold.
 ///
+/// # Order statistics
+///
+/// A range can be treated as a sorted sequence of its elements.
+/// [`rank`](Ix::rank) counts the elements preceding a value,
+/// [`select`](Ix::select) finds the element with a given rank,
+/// and [`count_between`](Ix::count_between) counts the elements between two values.
+/// By property 2, `Ix::select(x.rank(min, max), min, max) == x` for every `x` in the range.
+///
 /// # Examples
 ///
 /// ```
@@ -128,53 +208,3351 @@ pub trait Ix: PartialOrd + Sized {
     ///
     /// [`range_size`]: Ix::range_size
     fn range_size_checked(min: Self, max: Self) -> Option<usize>;
-}
-
-macro_rules! assert_ordered {
-    ($min: expr, $max: expr) => {
-        if $min > $max {
-            panic!("min is greater than max");
+    /// Get the length of a range.
+    /// If this would overflow the range of [`usize`], returns `default`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::range_size_or(3u8, 7, 0), 5);
+    /// assert_eq!(Ix::range_size_or(0u128, u128::MAX, 0), 0);
+    /// ```
+    fn range_size_or(min: Self, max: Self, default: usize) -> usize {
+        Ix::range_size_checked(min, max).unwrap_or(default)
+    }
+    /// Get the length of a range, converted to another integer type.
+    /// If the length is not representable as a [`usize`] value or as a value of type `O`,
+    /// returns [`None`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::range_size_as::<u32>(0i32, 1000), Some(1001));
+    /// assert_eq!(Ix::range_size_as::<u8>(0i32, 1000), None);
+    /// ```
+    fn range_size_as<O: TryFrom<usize>>(min: Self, max: Self) -> Option<O> {
+        O::try_from(Ix::range_size_checked(min, max)?).ok()
+    }
+    /// Get the length of a range as a [`NonZeroUsize`](core::num::NonZeroUsize) value,
+    /// since every range has at least one element.
+    /// If this would overflow the range of [`usize`], returns [`None`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// use core::num::NonZeroUsize;
+    ///
+    /// fn chunk_count(len: usize, chunk: NonZeroUsize) -> usize {
+    ///     len.div_ceil(chunk.get())
+    /// }
+    ///
+    /// let size = Ix::range_size_nonzero(1u8, 4).unwrap();
+    /// assert_eq!(chunk_count(10, size), 3);
+    /// assert_eq!(Ix::range_size_nonzero('x', 'x'), NonZeroUsize::new(1));
+    /// assert_eq!(Ix::range_size_nonzero(0u128, u128::MAX), None);
+    /// ```
+    fn range_size_nonzero(min: Self, max: Self) -> Option<core::num::NonZeroUsize> {
+        core::num::NonZeroUsize::new(Ix::range_size_checked(min, max)?)
+    }
+    /// Get the value at a position inside a range.
+    /// Inverse of [`index`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if `index` is not less than the size of the range.
+    /// The default implementation does this by unwrapping the return value of [`from_index_checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::from_index(3, -5i32, 5), -2);
+    /// assert_eq!(Ix::from_index((-2i32).index(-5, 5), -5, 5), -2);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`from_index_checked`]: Ix::from_index_checked
+    fn from_index(index: usize, min: Self, max: Self) -> Self {
+        Ix::from_index_checked(index, min, max).expect("index out of range")
+    }
+    /// Get the value at a position inside a range.
+    /// If `index` is not less than the size of the range, returns [`None`].
+    /// Checked version of [`from_index`].
+    ///
+    /// The default implementation iterates over the range.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::from_index_checked(255, 0u8, 255), Some(255));
+    /// assert_eq!(Ix::from_index_checked(256, 0u8, 255), None);
+    /// ```
+    ///
+    /// [`from_index`]: Ix::from_index
+    fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+        Ix::range(min, max).nth(index)
+    }
+    /// Get the first element of a range, which is `min`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::first((0u8, 'a'), (3, 'z')), (0, 'a'));
+    /// ```
+    fn first(min: Self, max: Self) -> Self {
+        assert_ordered!(min, max);
+        min
+    }
+    /// Get the last element of a range, which is `max`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::last((0u8, 'a'), (3, 'z')), (3, 'z'));
+    /// ```
+    fn last(min: Self, max: Self) -> Self {
+        assert_ordered!(min, max);
+        max
+    }
+    /// Get the element at a position inside a range.
+    /// If `index` is not less than the size of the range, returns [`None`].
+    /// Equivalent to [`from_index_checked`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::nth(2, 10u8, 20), Some(12));
+    /// assert_eq!(Ix::nth(11, 10u8, 20), None);
+    /// ```
+    ///
+    /// [`from_index_checked`]: Ix::from_index_checked
+    fn nth(index: usize, min: Self, max: Self) -> Option<Self> {
+        Ix::from_index_checked(index, min, max)
+    }
+    /// Get the number of elements that two ranges have in common, without iterating over them.
+    /// If the ranges are disjoint, returns `0`.
+    ///
+    /// For composite types, this is the product of the overlap sizes of the components,
+    /// since the ranges describe boxes.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the least element of either range is greater than its greatest element.
+    ///
+    /// Panics if the size of the overlap is not representable as a [`usize`] value.
+    /// The default implementation does this by unwrapping the return value of [`overlap_size_checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::overlap_size((0i32, 9), (5, 14)), 5);
+    /// assert_eq!(Ix::overlap_size((0i32, 9), (10, 14)), 0);
+    /// assert_eq!(Ix::overlap_size(((0u8, 0u8), (3, 3)), ((2, 1), (5, 2))), 4);
+    /// ```
+    ///
+    /// [`overlap_size_checked`]: Ix::overlap_size_checked
+    fn overlap_size(a: (Self, Self), b: (Self, Self)) -> usize {
+        Ix::overlap_size_checked(a, b).expect("range size too large")
+    }
+    /// Get the number of elements that two ranges have in common, without iterating over them.
+    /// If the ranges are disjoint, returns `0`.
+    /// If the size of the overlap is not representable as a [`usize`] value, returns [`None`].
+    /// Checked version of [`overlap_size`].
+    ///
+    /// The default implementation intersects the ranges as intervals of the ordering of the type,
+    /// which is only correct for one-dimensional types.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the least element of either range is greater than its greatest element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::overlap_size_checked(('a', 'z'), ('x', '~')), Some(3));
+    /// assert_eq!(Ix::overlap_size_checked((0u128, u128::MAX), (1, u128::MAX)), None);
+    /// ```
+    ///
+    /// [`overlap_size`]: Ix::overlap_size
+    fn overlap_size_checked(a: (Self, Self), b: (Self, Self)) -> Option<usize> {
+        let ((a_min, a_max), (b_min, b_max)) = (a, b);
+        assert_ordered!(a_min, a_max);
+        assert_ordered!(b_min, b_max);
+        let min = if a_min < b_min { b_min } else { a_min };
+        let max = if a_max > b_max { b_max } else { a_max };
+        if min > max {
+            Some(0)
+        } else {
+            Ix::range_size_checked(min, max)
         }
-    };
-}
-
-macro_rules! assert_in_range {
-    ($min: expr, $max: expr, $ix: expr) => {
-        if $ix < $min {
-            panic!("index is outside range (< min)");
-        } else if $ix > $max {
-            panic!("index is outside range (> max)");
+    }
+    /// Get the bounds of the smallest range containing two values, in either order.
+    /// Unlike [`Bounds::new`], this never panics because of the order of its arguments.
+    ///
+    /// For composite types, the bounds are determined componentwise,
+    /// so the result is the bounding box of the two values rather than the two values themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Bounds, Ix};
+    /// assert_eq!(Ix::bounds_sorted(9u8, 3), Bounds::new(3, 9));
+    /// assert_eq!(Ix::bounds_sorted((0u8, 5u8), (3, 1)), Bounds::new((0, 1), (3, 5)));
+    /// ```
+    fn bounds_sorted(a: Self, b: Self) -> Bounds<Self> {
+        if a > b {
+            Bounds::new(b, a)
+        } else {
+            Bounds::new(a, b)
         }
-    };
-}
-
-macro_rules! impl_ix_numeric {
-    ($($t: ty),+) => {
-        $(
-            impl $crate::Ix for $t {
-                type Range = ::core::ops::RangeInclusive<$t>;
-                fn range(min: Self, max: Self) -> Self::Range {
-                    assert_ordered!(min, max);
-                    min..=max
-                }
-                fn index_checked(self, min: Self, max: Self) -> Option<usize> {
-                    assert_ordered!(min, max);
-                    assert_in_range!(min, max, self);
-                    usize::try_from(self - min).ok()
-                }
-                fn in_range(self, min: Self, max: Self) -> bool {
-                    assert_ordered!(min, max);
-                    min <= self && self <= max
-                }
-                fn range_size_checked(min: Self, max: Self) -> Option<usize> {
-                    assert_ordered!(min, max);
-                    usize::try_from(max - min)
-                        .ok()
-                        .and_then(|n| n.checked_add(1))
-                }
+    }
+    /// Generate an iterator over the range described by `bounds`.
+    /// Equivalent to [`range`] with the least and greatest element of `bounds`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Bounds, Ix};
+    /// assert!(Ix::range_in(&Bounds::new(3u8, 6)).eq([3, 4, 5, 6]));
+    /// ```
+    ///
+    /// [`range`]: Ix::range
+    fn range_in(bounds: &Bounds<Self>) -> Self::Range
+    where
+        Self: Clone,
+    {
+        Ix::range(bounds.min().clone(), bounds.max().clone())
+    }
+    /// Get the position of a value inside the range described by `bounds`.
+    /// Equivalent to [`index`] with the least and greatest element of `bounds`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// Panics if the resulting index is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Bounds, Ix};
+    /// let bounds = Bounds::new(-10i64, 10);
+    /// assert_eq!((-10).index_in(&bounds), 0);
+    /// assert_eq!(10.index_in(&bounds), 20);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`in_range`]: Ix::in_range
+    fn index_in(self, bounds: &Bounds<Self>) -> usize
+    where
+        Self: Clone,
+    {
+        self.index(bounds.min().clone(), bounds.max().clone())
+    }
+    /// Get the position of a value inside the range described by `bounds`.
+    /// If this would overflow the range of [`usize`], returns [`None`].
+    /// Equivalent to [`index_checked`] with the least and greatest element of `bounds`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Bounds, Ix};
+    /// assert_eq!(7u128.index_checked_in(&Bounds::new(0, u128::MAX)), Some(7));
+    /// assert_eq!(u128::MAX.index_checked_in(&Bounds::new(0, u128::MAX)), None);
+    /// ```
+    ///
+    /// [`index_checked`]: Ix::index_checked
+    /// [`in_range`]: Ix::in_range
+    fn index_checked_in(self, bounds: &Bounds<Self>) -> Option<usize>
+    where
+        Self: Clone,
+    {
+        self.index_checked(bounds.min().clone(), bounds.max().clone())
+    }
+    /// Check if a given value is inside the range described by `bounds`.
+    /// Equivalent to [`in_range`] with the least and greatest element of `bounds`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Bounds, Ix};
+    /// let bounds = Bounds::new(1u16, 9);
+    /// assert!(5.in_bounds(&bounds));
+    /// assert!(!0.in_bounds(&bounds));
+    /// ```
+    ///
+    /// [`in_range`]: Ix::in_range
+    fn in_bounds(self, bounds: &Bounds<Self>) -> bool
+    where
+        Self: Clone,
+    {
+        self.in_range(bounds.min().clone(), bounds.max().clone())
+    }
+    /// Check whether a range contains a value or another range, depending on the variant of `query`.
+    /// For [`Contains::Point`], this is [`in_range`].
+    /// For [`Contains::Range`], this checks that both endpoints of the queried range are in `outer`;
+    /// for composite types, this means that the box described by the queried range lies within the box described by `outer`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the least element of `outer` is greater than its greatest element.
+    ///
+    /// Should panic if the least element of a queried range is greater than its greatest element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Contains, Ix};
+    /// assert!(Ix::contains((0i32, 9), Contains::Point(4)));
+    /// assert!(Ix::contains((0i32, 9), Contains::Range(2, 9)));
+    /// assert!(!Ix::contains((0i32, 9), Contains::Range(5, 10)));
+    /// ```
+    ///
+    /// [`in_range`]: Ix::in_range
+    fn contains(outer: (Self, Self), query: Contains<Self>) -> bool
+    where
+        Self: Clone,
+    {
+        let (min, max) = outer;
+        match query {
+            Contains::Point(ix) => ix.in_range(min, max),
+            Contains::Range(lo, hi) => {
+                assert_ordered!(lo, hi);
+                lo.in_range(min.clone(), max.clone()) && hi.in_range(min, max)
             }
-        )+
-    };
-}
-
-impl_ix_numeric!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize, isize);
+        }
+    }
+    /// Check whether a value is inside a range, without computing its position.
+    /// If it is not, returns an error carrying the value
+    /// and whether it is less than `min` or not.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Ix, OutOfRange};
+    /// assert_eq!(5i32.check_in_range(0, 9), Ok(()));
+    /// assert_eq!((-1i32).check_in_range(0, 9), Err(OutOfRange::BelowMin(-1)));
+    /// assert_eq!(10i32.check_in_range(0, 9), Err(OutOfRange::AboveMax(10)));
+    /// ```
+    fn check_in_range(self, min: Self, max: Self) -> Result<(), OutOfRange<Self>>
+    where
+        Self: Clone,
+    {
+        if self.clone().in_range(min.clone(), max) {
+            Ok(())
+        } else if self < min {
+            Err(OutOfRange::BelowMin(self))
+        } else {
+            Err(OutOfRange::AboveMax(self))
+        }
+    }
+    /// Get the position of a value inside a range, converted to another integer type.
+    /// If the position is not representable as a [`usize`] value or as a value of type `O`,
+    /// returns [`None`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(1010i32.index_as::<u8>(1000, 2000), Some(10));
+    /// assert_eq!(1900i32.index_as::<u8>(1000, 2000), None);
+    /// ```
+    ///
+    /// [`in_range`]: Ix::in_range
+    fn index_as<O: TryFrom<usize>>(self, min: Self, max: Self) -> Option<O> {
+        O::try_from(self.index_checked(min, max)?).ok()
+    }
+    /// Get the value at a position inside a range, where the position is given as another integer type.
+    /// If `index` is not representable as a [`usize`] value or is not less than the size of the range,
+    /// returns [`None`].
+    /// Counterpart of [`index_as`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::from_index_as(10u32, 1000i32, 2000), Some(1010));
+    /// assert_eq!(Ix::from_index_as(-1i8, 1000i32, 2000), None);
+    /// ```
+    ///
+    /// [`index_as`]: Ix::index_as
+    fn from_index_as<O: TryInto<usize>>(index: O, min: Self, max: Self) -> Option<Self> {
+        Ix::from_index_checked(index.try_into().ok()?, min, max)
+    }
+    /// Write the elements of a range into a slice, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `out` differs from the size of the range.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let mut out = [0i32; 5];
+    /// Ix::fill_slice(10, 14, &mut out);
+    /// assert_eq!(out, [10, 11, 12, 13, 14]);
+    /// ```
+    fn fill_slice(min: Self, max: Self, out: &mut [Self])
+    where
+        Self: Clone,
+    {
+        Ix::fill_slice_with(min, max, out, |ix| ix)
+    }
+    /// Write the results of applying `f` to the elements of a range into a slice, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `out` differs from the size of the range.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let mut out = [0i64; 4];
+    /// Ix::fill_slice_with(1i32, 4, &mut out, |x| i64::from(x) * 10);
+    /// assert_eq!(out, [10, 20, 30, 40]);
+    /// ```
+    fn fill_slice_with<U, F: FnMut(Self) -> U>(min: Self, max: Self, out: &mut [U], f: F)
+    where
+        Self: Clone,
+    {
+        if let Err(err) = Ix::try_fill_slice_with(min, max, out, f) {
+            panic!("{}", err);
+        }
+    }
+    /// Write the elements of a range into a slice, in order.
+    /// If the length of `out` differs from the size of the range, returns an error
+    /// and leaves `out` unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Ix, LengthMismatch};
+    /// let mut out = [0u8; 3];
+    /// assert_eq!(
+    ///     Ix::try_fill_slice(0u8, 3, &mut out),
+    ///     Err(LengthMismatch { expected: Some(4), actual: 3 })
+    /// );
+    /// assert_eq!(Ix::try_fill_slice(1u8, 3, &mut out), Ok(()));
+    /// assert_eq!(out, [1, 2, 3]);
+    /// ```
+    fn try_fill_slice(min: Self, max: Self, out: &mut [Self]) -> Result<(), LengthMismatch>
+    where
+        Self: Clone,
+    {
+        Ix::try_fill_slice_with(min, max, out, |ix| ix)
+    }
+    /// Write the results of applying `f` to the elements of a range into a slice, in order.
+    /// If the length of `out` differs from the size of the range, returns an error
+    /// and leaves `out` unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    fn try_fill_slice_with<U, F: FnMut(Self) -> U>(
+        min: Self,
+        max: Self,
+        out: &mut [U],
+        f: F,
+    ) -> Result<(), LengthMismatch>
+    where
+        Self: Clone,
+    {
+        let expected = Ix::range_size_checked(min.clone(), max.clone());
+        if expected != Some(out.len()) {
+            return Err(LengthMismatch {
+                expected,
+                actual: out.len(),
+            });
+        }
+        for (slot, value) in out.iter_mut().zip(Ix::range(min, max).map(f)) {
+            *slot = value;
+        }
+        Ok(())
+    }
+    /// Write the positions of the values in a slice inside a range into another slice, in order.
+    /// If a value is not in the range, returns an error carrying the first such value,
+    /// as determined by [`check_in_range`].
+    /// In that case, the positions of the values preceding it have already been written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `out` differs from the length of `values`.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if a position is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Ix, OutOfRange};
+    /// let mut out = [0; 3];
+    /// assert_eq!(Ix::index_batch(&[12i32, 10, 19], 10, 19, &mut out), Ok(()));
+    /// assert_eq!(out, [2, 0, 9]);
+    /// assert_eq!(
+    ///     Ix::index_batch(&[12i32, 20, 9], 10, 19, &mut out),
+    ///     Err(OutOfRange::AboveMax(20))
+    /// );
+    /// ```
+    ///
+    /// [`check_in_range`]: Ix::check_in_range
+    fn index_batch(
+        values: &[Self],
+        min: Self,
+        max: Self,
+        out: &mut [usize],
+    ) -> Result<(), OutOfRange<Self>>
+    where
+        Self: Clone,
+    {
+        if values.len() != out.len() {
+            panic!("output length differs from input length");
+        }
+        for (slot, value) in out.iter_mut().zip(values) {
+            value.clone().check_in_range(min.clone(), max.clone())?;
+            *slot = value.clone().index(min.clone(), max.clone());
+        }
+        Ok(())
+    }
+    /// Gather the results of applying `f` to the elements of a source range,
+    /// given as a pair of its least and greatest element, into a destination slice, in order.
+    /// Equivalent to [`fill_slice_with`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `dst` differs from the size of the range.
+    ///
+    /// Should panic if the least element of `src` is greater than the greatest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let mut dst = [0i64; 5];
+    /// Ix::gather_into((0i32, 4), &mut dst, |x| i64::from(x * x));
+    /// assert_eq!(dst, [0, 1, 4, 9, 16]);
+    /// ```
+    ///
+    /// [`fill_slice_with`]: Ix::fill_slice_with
+    fn gather_into<U, F: FnMut(Self) -> U>(src: (Self, Self), dst: &mut [U], f: F)
+    where
+        Self: Clone,
+    {
+        Ix::fill_slice_with(src.0, src.1, dst, f)
+    }
+    /// Check whether a buffer of `total` elements has exactly the shape of
+    /// an outer range of rows, each holding an inner range of elements,
+    /// which is the case if the product of the sizes of both ranges is `total`.
+    ///
+    /// Returns `false` if the size of either range or their product is not representable as a [`usize`] value.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the least element of `outer` or `inner` is greater than its greatest element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::shape_fits((0u8, 2), (0, 3), 12));
+    /// assert!(!Ix::shape_fits((0u8, 2), (0, 3), 13));
+    /// ```
+    fn shape_fits(outer: (Self, Self), inner: (Self, Self), total: usize) -> bool {
+        let outer = Ix::range_size_checked(outer.0, outer.1);
+        let inner = Ix::range_size_checked(inner.0, inner.1);
+        outer
+            .zip(inner)
+            .and_then(|(outer, inner)| outer.checked_mul(inner))
+            == Some(total)
+    }
+    /// Get a capacity hint for a collection with one entry per element of a range,
+    /// such as a map keyed by the elements.
+    /// This is the size of the range.
+    ///
+    /// Returns [`None`] if the size of the range is greater than [`isize::MAX`],
+    /// since no collection can reserve that many entries.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// use std::collections::HashMap;
+    ///
+    /// let capacity = Ix::map_capacity((0u8, 0u8), (3, 3)).unwrap_or(0);
+    /// assert_eq!(capacity, 16);
+    /// let map: HashMap<(u8, u8), f64> = HashMap::with_capacity(capacity);
+    /// assert!(map.capacity() >= 16);
+    /// assert_eq!(Ix::map_capacity(0u64, u64::MAX), None);
+    /// ```
+    fn map_capacity(min: Self, max: Self) -> Option<usize> {
+        Ix::range_size_checked(min, max).filter(|&size| size <= isize::MAX as usize)
+    }
+    /// Generate an iterator over a range starting from `min` and stopping at `max`
+    /// that keeps track of how many elements remain.
+    ///
+    /// Returns [`None`] if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let mut range = Ix::counted_range(1u8, 3).unwrap();
+    /// assert_eq!(range.remaining(), 3);
+    /// assert_eq!(range.next(), Some(1));
+    /// assert_eq!(range.remaining(), 2);
+    /// assert!(Ix::counted_range(0u128, u128::MAX).is_none());
+    /// ```
+    fn counted_range(min: Self, max: Self) -> Option<CountedRange<Self>>
+    where
+        Self: Clone,
+    {
+        let remaining = Ix::range_size_checked(min.clone(), max.clone())?;
+        Some(CountedRange::new(Ix::range(min, max), remaining))
+    }
+    /// Generate an iterator over a range starting from `min` and stopping at `max`
+    /// that produces every element along with `min` and `max`, as a triple.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let ratios: Vec<_> = Ix::iter_with_bounds(0i32, 4)
+    ///     .map(|(x, lo, hi)| x.span_ratio(lo, hi))
+    ///     .collect();
+    /// assert_eq!(ratios, [0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    fn iter_with_bounds(min: Self, max: Self) -> WithBounds<Self>
+    where
+        Self: Clone,
+    {
+        WithBounds::new(min, max)
+    }
+    /// Generate an iterator that produces the elements of a range starting from `min` and stopping at `max`
+    /// in order, starting over from `min` after reaching `max`, forever.
+    ///
+    /// Unlike cycling over [`range`], this does not require [`Range`] to implement [`Clone`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::cycle_range(1u8, 3).take(7).eq([1, 2, 3, 1, 2, 3, 1]));
+    /// assert!(Ix::cycle_range('x', 'x').take(3).eq(['x'; 3]));
+    /// ```
+    ///
+    /// [`range`]: Ix::range
+    /// [`Range`]: Ix::Range
+    fn cycle_range(min: Self, max: Self) -> CycleRange<Self>
+    where
+        Self: Clone,
+    {
+        CycleRange::new(min, max)
+    }
+    /// Generate an iterator over the elements of a range starting from `min` and stopping at `max`
+    /// that lie between `start` and `end`, in the order of the range.
+    /// If `start` or `end` is less than `min` or greater than `max`, it is clipped to `min` or `max`, respectively.
+    /// If `start` comes after `end`, the iterator is empty.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `start` or `end` is neither in the range nor less than `min` nor greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::range_within(-5i32, 2, 0, 9).eq(0..=2));
+    /// assert!(Ix::range_within(7i32, 20, 0, 9).eq(7..=9));
+    /// assert!(Ix::range_within((0u8, 2u8), (1, 1), (0, 0), (2, 2)).eq([(0, 2), (1, 0), (1, 1)]));
+    /// ```
+    fn range_within(start: Self, end: Self, min: Self, max: Self) -> RangeWithin<Self>
+    where
+        Self: Clone,
+    {
+        RangeWithin::new(start, end, min, max)
+    }
+    /// Generate an iterator over pairs of elements of a range starting from `min` and stopping at `max`,
+    /// taken from both ends and moving towards the middle:
+    /// first `min` and `max`, then their successor and predecessor, and so on.
+    ///
+    /// If the size of the range is odd, the last pair consists of the middle element paired with itself.
+    /// If it is even, the last pair consists of the two middle elements.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::converging_pairs(0i32, 5).eq([(0, 5), (1, 4), (2, 3)]));
+    /// assert!(Ix::converging_pairs(0i32, 4).eq([(0, 4), (1, 3), (2, 2)]));
+    /// ```
+    fn converging_pairs(min: Self, max: Self) -> ConvergingPairs<Self>
+    where
+        Self: Clone,
+    {
+        ConvergingPairs::new(min, max)
+    }
+    /// Collect the elements of a range into a [`Vec`](alloc::vec::Vec), allocating it up front.
+    ///
+    /// Returns [`None`] if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::try_collect_vec(3u8, 6), Some(vec![3, 4, 5, 6]));
+    /// assert_eq!(Ix::try_collect_vec(0u128, u128::MAX), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_collect_vec(min: Self, max: Self) -> Option<alloc::vec::Vec<Self>>
+    where
+        Self: Clone,
+    {
+        let mut vec =
+            alloc::vec::Vec::with_capacity(Ix::range_size_checked(min.clone(), max.clone())?);
+        vec.extend(Ix::range(min, max));
+        Some(vec)
+    }
+    /// Collect the elements of a range into a [`BTreeMap`](alloc::collections::BTreeMap),
+    /// mapping each element to the result of applying `f` to it.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let map = Ix::to_btreemap(1u8, 3, |x| x * 10);
+    /// assert!(map.into_iter().eq([(1, 10), (2, 20), (3, 30)]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_btreemap<V, F: FnMut(Self) -> V>(
+        min: Self,
+        max: Self,
+        mut f: F,
+    ) -> alloc::collections::BTreeMap<Self, V>
+    where
+        Self: Clone + Ord,
+    {
+        Ix::range(min, max).map(|ix| (ix.clone(), f(ix))).collect()
+    }
+    /// Describe a range starting from `min` and stopping at `max`, for example for logging.
+    /// The result implements [`Display`](fmt::Display), formatting the endpoints with [`Debug`](fmt::Debug),
+    /// followed by the size of the range, or `overflows usize` if it is not representable as a [`usize`] value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::describe(-5i32, 10).to_string(), "[-5..=10] (16 elements)");
+    /// assert_eq!(Ix::describe('a', 'a').to_string(), "['a'..='a'] (1 element)");
+    /// assert_eq!(Ix::describe(0u128, u128::MAX).to_string(), format!("[0..={}] (overflows usize)", u128::MAX));
+    /// ```
+    fn describe(min: Self, max: Self) -> Describe<Self>
+    where
+        Self: Clone + fmt::Debug,
+    {
+        Describe::new(min, max)
+    }
+    /// Write every element of a range along with its position, one per line.
+    /// Each line has the form `index=<position> value=<element>`, where the element is formatted with [`Debug`](fmt::Debug).
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let mut out = String::new();
+    /// Ix::debug_dump_to(4u8, 5, &mut out).unwrap();
+    /// assert_eq!(out, "index=0 value=4\nindex=1 value=5\n");
+    /// ```
+    fn debug_dump_to<W: fmt::Write>(min: Self, max: Self, w: &mut W) -> fmt::Result
+    where
+        Self: fmt::Debug,
+    {
+        for (index, ix) in Ix::range(min, max).enumerate() {
+            writeln!(w, "index={} value={:?}", index, ix)?;
+        }
+        Ok(())
+    }
+    /// Print every element of a range along with its position to standard output, one per line,
+    /// in the format of [`debug_dump_to`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// [`debug_dump_to`]: Ix::debug_dump_to
+    #[cfg(feature = "std")]
+    fn debug_dump(min: Self, max: Self)
+    where
+        Self: fmt::Debug,
+    {
+        for (index, ix) in Ix::range(min, max).enumerate() {
+            std::println!("index={} value={:?}", index, ix);
+        }
+    }
+    /// Find the first element of a range satisfying a predicate, along with its position.
+    /// The returned position is the value's [`index`] in the range.
+    ///
+    /// Returns [`None`] if no element of the range satisfies `pred`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::find_index(0i32, 20, |x| x % 7 == 0), Some((0, 0)));
+    /// assert_eq!(Ix::find_index(0i32, 20, |x| *x > 0 && x % 7 == 0), Some((7, 7)));
+    /// assert_eq!(Ix::find_index(-20i32, 0, |x| x % 7 == 0), Some((6, -14)));
+    /// assert_eq!(Ix::find_index(0u8, 5, |x| *x > 5), None);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    fn find_index<P: FnMut(&Self) -> bool>(
+        min: Self,
+        max: Self,
+        mut pred: P,
+    ) -> Option<(usize, Self)> {
+        Ix::range(min, max).enumerate().find(|(_, ix)| pred(ix))
+    }
+    /// Check that a range is partitioned by a predicate,
+    /// such that all elements satisfying it precede all elements that don't,
+    /// as [`partition_point`] assumes.
+    /// This walks the whole range, but only when debug assertions are enabled;
+    /// otherwise, it does nothing.
+    ///
+    /// # Panics
+    ///
+    /// If debug assertions are enabled, panics if an element satisfying `pred` follows one that doesn't,
+    /// with a message giving the positions of the two elements.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// Ix::assert_monotone(0i32, 99, |x| *x < 50);
+    /// ```
+    /// ```should_panic
+    /// # use ix_rs::Ix;
+    /// # assert!(cfg!(debug_assertions));
+    /// Ix::assert_monotone(0i32, 99, |x| x % 2 == 0);
+    /// ```
+    ///
+    /// [`partition_point`]: Ix::partition_point
+    fn assert_monotone<P: FnMut(&Self) -> bool>(min: Self, max: Self, mut pred: P) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        let mut first_false = None;
+        for (index, ix) in Ix::range(min, max).enumerate() {
+            match (pred(&ix), first_false) {
+                (true, Some(false_index)) => panic!(
+                    "predicate is true at position {} after being false at position {}",
+                    index, false_index
+                ),
+                (false, None) => first_false = Some(index),
+                _ => {}
+            }
+        }
+    }
+    /// Find the first element of a range that does not satisfy a predicate, using binary search.
+    /// The range is assumed to be partitioned by `pred`,
+    /// such that all elements satisfying it precede all elements that don't.
+    /// In debug builds, this is checked with [`assert_monotone`] before searching, which walks the whole range.
+    /// Otherwise, if the range is not partitioned, any element where `pred` changes from true to false may be returned.
+    ///
+    /// Returns [`None`] if every element of the range satisfies `pred`.
+    ///
+    /// # Panics
+    ///
+    /// If debug assertions are enabled, panics if the range is not partitioned by `pred`.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::partition_point(0i32, 99, |x| *x < 50), Some(50));
+    /// assert_eq!(Ix::partition_point(0i32, 99, |x| x * x < 2000), Some(45));
+    /// assert_eq!(Ix::partition_point(0i32, 99, |_| true), None);
+    /// ```
+    ///
+    /// [`assert_monotone`]: Ix::assert_monotone
+    fn partition_point<P: FnMut(&Self) -> bool>(min: Self, max: Self, mut pred: P) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        #[cfg(debug_assertions)]
+        Ix::assert_monotone(min.clone(), max.clone(), &mut pred);
+        let size = Ix::range_size(min.clone(), max.clone());
+        let (mut low, mut high) = (0, size);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if pred(&Ix::from_index(mid, min.clone(), max.clone())) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ix::from_index_checked(low, min, max)
+    }
+    /// Count the consecutive elements at the start of a range that satisfy a predicate,
+    /// stopping at the first element that doesn't.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::count_leading(0i32, 9, |x| x % 2 == 0), 1);
+    /// assert_eq!(Ix::count_leading(0i32, 9, |x| *x < 4), 4);
+    /// ```
+    fn count_leading<P: FnMut(&Self) -> bool>(min: Self, max: Self, pred: P) -> usize {
+        Ix::range(min, max).take_while(pred).count()
+    }
+    /// Count the consecutive elements at the end of a range that satisfy a predicate,
+    /// stopping at the last element that doesn't.
+    ///
+    /// The default implementation walks the range backwards using [`predecessor`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::count_trailing(0i32, 9, |x| x % 2 == 0), 0);
+    /// assert_eq!(Ix::count_trailing(0i32, 9, |x| *x > 6), 3);
+    /// ```
+    ///
+    /// [`predecessor`]: Ix::predecessor
+    fn count_trailing<P: FnMut(&Self) -> bool>(min: Self, max: Self, mut pred: P) -> usize
+    where
+        Self: Clone,
+    {
+        assert_ordered!(min, max);
+        let mut count = 0;
+        let mut next = Some(max.clone());
+        while let Some(ix) = next {
+            if !pred(&ix) {
+                break;
+            }
+            count += 1;
+            next = ix.predecessor(min.clone(), max.clone());
+        }
+        count
+    }
+    /// Generate an iterator that threads a state through the elements of a range in order,
+    /// like [`Iterator::scan`] over [`range`].
+    /// The closure receives a mutable reference to the state and the current element,
+    /// and the iterator stops as soon as it returns [`None`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let sums = Ix::scan_indices(1i32, 5, 0, |sum, x| {
+    ///     *sum += x;
+    ///     Some(*sum)
+    /// });
+    /// assert!(sums.eq([1, 3, 6, 10, 15]));
+    /// ```
+    ///
+    /// [`range`]: Ix::range
+    fn scan_indices<S, B, F: FnMut(&mut S, Self) -> Option<B>>(
+        min: Self,
+        max: Self,
+        state: S,
+        f: F,
+    ) -> core::iter::Scan<Self::Range, S, F> {
+        Ix::range(min, max).scan(state, f)
+    }
+    /// Combine the elements of a range into a single value, in order, starting from `init`.
+    /// This iterates over the range; for sums of numeric ranges,
+    /// [`NumericIx::reduce_sum_u128`] and [`NumericIx::sum_range`] use a closed-form formula instead.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::reduce(1u8, 5, 1u32, |acc, x| acc * u32::from(x)), 120);
+    /// assert_eq!(Ix::reduce('a', 'e', 0, |count, c| count + usize::from("aeiou".contains(c))), 2);
+    /// ```
+    fn reduce<B, F: FnMut(B, Self) -> B>(min: Self, max: Self, init: B, op: F) -> B {
+        Ix::range(min, max).fold(init, op)
+    }
+    /// Call a closure on every element of a range in order, stopping as soon as it returns [`ControlFlow::Break`].
+    /// Returns the break value, or [`ControlFlow::Continue`] if the closure never broke.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// use core::ops::ControlFlow;
+    /// let result = Ix::try_for_each_index(1i32, 100, |x| {
+    ///     if x * x > 50 {
+    ///         ControlFlow::Break(x)
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    /// assert_eq!(result, ControlFlow::Break(8));
+    /// ```
+    ///
+    /// [`ControlFlow::Break`]: core::ops::ControlFlow::Break
+    /// [`ControlFlow::Continue`]: core::ops::ControlFlow::Continue
+    fn try_for_each_index<B, F: FnMut(Self) -> core::ops::ControlFlow<B>>(
+        min: Self,
+        max: Self,
+        f: F,
+    ) -> core::ops::ControlFlow<B> {
+        Ix::range(min, max).try_for_each(f)
+    }
+    /// Fold every element of a range in order into an accumulator with a fallible closure,
+    /// stopping at the first error and returning it.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::try_fold_indices(1u8, 5, 0u8, |acc, x| acc.checked_add(x).ok_or(x)), Ok(15));
+    /// assert_eq!(Ix::try_fold_indices(100u8, 200, 0u8, |acc, x| acc.checked_add(x).ok_or(x)), Err(102));
+    /// ```
+    fn try_fold_indices<B, E, F: FnMut(B, Self) -> Result<B, E>>(
+        min: Self,
+        max: Self,
+        init: B,
+        f: F,
+    ) -> Result<B, E> {
+        Ix::range(min, max).try_fold(init, f)
+    }
+    /// Sum the positions of several values inside a range.
+    /// The positions are computed and accumulated as [`u128`] values, as determined by [`index_u128`].
+    ///
+    /// Returns [`None`] if any of the values is not in the range (as determined by [`in_range`]),
+    /// or if the sum overflows.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let points = [1000u32, 1003, 1999, 1500];
+    /// let total: u128 = points.iter().map(|&p| (p - 1000) as u128).sum();
+    /// assert_eq!(Ix::try_sum_indices(1000u32, 2000, points), Some(total));
+    /// assert_eq!(Ix::try_sum_indices(1000u32, 2000, [1000, 2001]), None);
+    /// assert_eq!(Ix::try_sum_indices(0u128, u128::MAX, [u128::MAX]), Some(u128::MAX));
+    /// ```
+    ///
+    /// [`index_u128`]: Ix::index_u128
+    /// [`in_range`]: Ix::in_range
+    fn try_sum_indices<I: IntoIterator<Item = Self>>(min: Self, max: Self, items: I) -> Option<u128>
+    where
+        Self: Clone,
+    {
+        let mut sum: u128 = 0;
+        for item in items {
+            if !item.clone().in_range(min.clone(), max.clone()) {
+                return None;
+            }
+            sum = sum.checked_add(item.index_u128(min.clone(), max.clone()))?;
+        }
+        Some(sum)
+    }
+    /// Check if the union of two ranges is itself a range,
+    /// which is the case if and only if they overlap or are directly adjacent.
+    ///
+    /// For composite types, the ranges describe boxes, so they are mergeable only if one contains the other,
+    /// or if they have the same bounds in every component but one and are mergeable in that component.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the least element of either range is greater than its greatest element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::is_mergeable((0i32, 4), (5, 9)));
+    /// assert!(Ix::is_mergeable((3i32, 7), (0, 4)));
+    /// assert!(!Ix::is_mergeable((0i32, 4), (6, 9)));
+    /// ```
+    fn is_mergeable(a: (Self, Self), b: (Self, Self)) -> bool
+    where
+        Self: Clone,
+    {
+        Ix::merge(a, b).is_some()
+    }
+    /// Get the union of two ranges if it is itself a range (as determined by [`is_mergeable`]).
+    /// Otherwise, returns [`None`].
+    ///
+    /// The default implementation compares the ranges as intervals of the ordering of the type,
+    /// which is only correct for one-dimensional types;
+    /// the implementations for tuples and arrays merge boxes componentwise.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the least element of either range is greater than its greatest element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::merge((0i32, 4), (5, 9)), Some((0, 9)));
+    /// assert_eq!(Ix::merge((0i32, 4), (6, 9)), None);
+    /// assert_eq!(Ix::merge((250u8, 255), (0, 249)), Some((0, 255)));
+    /// assert_eq!(Ix::merge(((0u8, 0u8), (1, 3)), ((2, 0), (4, 3))), Some(((0, 0), (4, 3))));
+    /// assert_eq!(Ix::merge(((0u8, 0u8), (1, 1)), ((2, 2), (3, 3))), None);
+    /// ```
+    ///
+    /// [`is_mergeable`]: Ix::is_mergeable
+    fn merge(a: (Self, Self), b: (Self, Self)) -> Option<(Self, Self)>
+    where
+        Self: Clone,
+    {
+        assert_ordered!(a.0, a.1);
+        assert_ordered!(b.0, b.1);
+        let (first, second) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+        if second.0 > first.1
+            && Ix::range_size_checked(first.1.clone(), second.0.clone()) != Some(2)
+        {
+            return None;
+        }
+        let max = if second.1 > first.1 {
+            second.1
+        } else {
+            first.1
+        };
+        Some((first.0, max))
+    }
+    /// Merge a list of ranges into a sorted list of disjoint ranges covering the same elements.
+    /// Ranges that overlap or are directly adjacent are merged, as determined by [`merge`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the least element of any range is greater than its greatest element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::normalize_ranges(&[(8i32, 10), (1, 3), (2, 5)]), [(1, 5), (8, 10)]);
+    /// assert_eq!(Ix::normalize_ranges(&[(1i32, 3), (4, 5)]), [(1, 5)]);
+    /// ```
+    ///
+    /// [`merge`]: Ix::merge
+    #[cfg(feature = "alloc")]
+    fn normalize_ranges(ranges: &[(Self, Self)]) -> alloc::vec::Vec<(Self, Self)>
+    where
+        Self: Clone + Ord,
+    {
+        let mut sorted = ranges.to_vec();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut normalized: alloc::vec::Vec<(Self, Self)> = alloc::vec::Vec::new();
+        for range in sorted {
+            match normalized.pop() {
+                Some(last) => match Ix::merge(last.clone(), range.clone()) {
+                    Some(merged) => normalized.push(merged),
+                    None => normalized.extend([last, range]),
+                },
+                None => normalized.push(range),
+            }
+        }
+        normalized
+    }
+    /// Get the sorted, disjoint ranges of elements of `outer` that are not in any of the ranges in `used`.
+    /// Ranges in `used` may extend beyond `outer`, in which case they are clipped to it.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the least element of `outer` or of any range in `used` is greater than its greatest element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::complement((0i32, 9), &[(2, 4)]), [(0, 1), (5, 9)]);
+    /// assert_eq!(Ix::complement((0i32, 9), &[(-5, 3), (7, 20)]), [(4, 6)]);
+    /// assert_eq!(Ix::complement((0i32, 9), &[(0, 9)]), []);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn complement(outer: (Self, Self), used: &[(Self, Self)]) -> alloc::vec::Vec<(Self, Self)>
+    where
+        Self: Clone + Ord,
+    {
+        let (min, max) = outer;
+        assert_ordered!(min, max);
+        let mut gaps = alloc::vec::Vec::new();
+        let mut next = Some(min.clone());
+        for (lo, hi) in Ix::normalize_ranges(used) {
+            let start = match next.clone() {
+                Some(start) => start,
+                None => break,
+            };
+            if hi < start || lo > max {
+                continue;
+            }
+            let lo = lo.max(min.clone());
+            if start < lo {
+                let last = lo.predecessor(min.clone(), max.clone());
+                gaps.push((start, last.expect("gap start is less than its end")));
+            }
+            next = hi.min(max.clone()).successor(min.clone(), max.clone());
+        }
+        if let Some(start) = next {
+            gaps.push((start, max));
+        }
+        gaps
+    }
+    /// Get the sorted, disjoint ranges of elements of `a` that are not in `b`,
+    /// of which there are at most two.
+    /// Equivalent to [`complement`] with `b` as the only used range.
+    ///
+    /// This is intended for one-dimensional types.
+    /// For composite types, ranges are treated as intervals in the order of the range `a`,
+    /// not as boxes, so the result is generally not the set difference of two boxes.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the least element of `a` or `b` is greater than its greatest element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::difference((0i32, 9), (3, 5)), [(0, 2), (6, 9)]);
+    /// assert_eq!(Ix::difference((0i32, 9), (5, 20)), [(0, 4)]);
+    /// ```
+    ///
+    /// [`complement`]: Ix::complement
+    #[cfg(feature = "alloc")]
+    fn difference(a: (Self, Self), b: (Self, Self)) -> alloc::vec::Vec<(Self, Self)>
+    where
+        Self: Clone + Ord,
+    {
+        Ix::complement(a, &[b])
+    }
+    /// Get the sorted, disjoint ranges of elements that are in exactly one of two ranges.
+    /// Directly adjacent pieces are merged, as determined by [`normalize_ranges`].
+    ///
+    /// This is intended for one-dimensional types, like [`difference`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if the least element of either range is greater than its greatest element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::symmetric_difference((0i32, 5), (3, 9)), [(0, 2), (6, 9)]);
+    /// assert_eq!(Ix::symmetric_difference((0i32, 4), (5, 9)), [(0, 9)]);
+    /// assert_eq!(Ix::symmetric_difference((0i32, 9), (0, 9)), []);
+    /// ```
+    ///
+    /// [`normalize_ranges`]: Ix::normalize_ranges
+    /// [`difference`]: Ix::difference
+    #[cfg(feature = "alloc")]
+    fn symmetric_difference(a: (Self, Self), b: (Self, Self)) -> alloc::vec::Vec<(Self, Self)>
+    where
+        Self: Clone + Ord,
+    {
+        let mut pieces = Ix::difference(a.clone(), b.clone());
+        pieces.extend(Ix::difference(b, a));
+        Ix::normalize_ranges(&pieces)
+    }
+    /// Sort and deduplicate a list of values, checking that each of them is inside a range.
+    /// If a value is outside the range, returns the first such value in `values` as an error.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::{Ix, OutOfRange};
+    /// assert_eq!(Ix::sorted_set(&[7u8, 2, 7, 5, 2], 0, 9), Ok(vec![2, 5, 7]));
+    /// assert_eq!(Ix::sorted_set(&[7u8, 12, 2], 0, 9), Err(OutOfRange::AboveMax(12)));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sorted_set(
+        values: &[Self],
+        min: Self,
+        max: Self,
+    ) -> Result<alloc::vec::Vec<Self>, OutOfRange<Self>>
+    where
+        Self: Ord + Copy,
+    {
+        assert_ordered!(min, max);
+        for &value in values {
+            value.check_in_range(min, max)?;
+        }
+        let mut set = values.to_vec();
+        set.sort_unstable();
+        set.dedup();
+        Ok(set)
+    }
+    /// Get the element following a value inside a range.
+    /// If the value is `max`, returns [`None`].
+    ///
+    /// The default implementation uses [`index`] and [`from_index_checked`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(4u8.successor(0, 5), Some(5));
+    /// assert_eq!(5u8.successor(0, 5), None);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`from_index_checked`]: Ix::from_index_checked
+    /// [`in_range`]: Ix::in_range
+    fn successor(self, min: Self, max: Self) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let index = self.index(min.clone(), max.clone()).checked_add(1)?;
+        Ix::from_index_checked(index, min, max)
+    }
+    /// Get the element preceding a value inside a range.
+    /// If the value is `min`, returns [`None`].
+    ///
+    /// The default implementation uses [`index`] and [`from_index_checked`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!((-4i8).predecessor(-5, 5), Some(-5));
+    /// assert_eq!((-5i8).predecessor(-5, 5), None);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`from_index_checked`]: Ix::from_index_checked
+    /// [`in_range`]: Ix::in_range
+    fn predecessor(self, min: Self, max: Self) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let index = self.index(min.clone(), max.clone()).checked_sub(1)?;
+        Ix::from_index_checked(index, min, max)
+    }
+    /// Get the element following a value inside a range.
+    /// If the value is `max`, returns `max`.
+    /// Saturating version of [`successor`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(3u8.successor_saturating(0, 9), 4);
+    /// assert_eq!(9u8.successor_saturating(0, 9), 9);
+    /// ```
+    ///
+    /// [`successor`]: Ix::successor
+    /// [`in_range`]: Ix::in_range
+    fn successor_saturating(self, min: Self, max: Self) -> Self
+    where
+        Self: Clone,
+    {
+        self.successor(min, max.clone()).unwrap_or(max)
+    }
+    /// Get the element preceding a value inside a range.
+    /// If the value is `min`, returns `min`.
+    /// Saturating version of [`predecessor`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(3u8.predecessor_saturating(0, 9), 2);
+    /// assert_eq!(0u8.predecessor_saturating(0, 9), 0);
+    /// ```
+    ///
+    /// [`predecessor`]: Ix::predecessor
+    /// [`in_range`]: Ix::in_range
+    fn predecessor_saturating(self, min: Self, max: Self) -> Self
+    where
+        Self: Clone,
+    {
+        self.predecessor(min.clone(), max).unwrap_or(min)
+    }
+    /// Get the element `n` positions after a value inside a range.
+    /// If this would go past `max`, returns [`None`].
+    ///
+    /// The default implementation uses [`index`] and [`from_index_checked`],
+    /// but the implementations for built-in numeric types never fail to find the element.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(2u8.advance(3, 0, 9), Some(5));
+    /// assert_eq!(2u8.advance(7, 0, 9), Some(9));
+    /// assert_eq!(2u8.advance(8, 0, 9), None);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`from_index_checked`]: Ix::from_index_checked
+    /// [`in_range`]: Ix::in_range
+    fn advance(self, n: usize, min: Self, max: Self) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let index = self.index(min.clone(), max.clone()).checked_add(n)?;
+        Ix::from_index_checked(index, min, max)
+    }
+    /// Get the element `n` positions before a value inside a range.
+    /// If this would go past `min`, returns [`None`].
+    ///
+    /// The default implementation uses [`index`] and [`from_index_checked`],
+    /// but the implementations for built-in numeric types never fail to find the element.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(5u8.retreat(3, 0, 9), Some(2));
+    /// assert_eq!(5u8.retreat(5, 0, 9), Some(0));
+    /// assert_eq!(5u8.retreat(6, 0, 9), None);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`from_index_checked`]: Ix::from_index_checked
+    /// [`in_range`]: Ix::in_range
+    fn retreat(self, n: usize, min: Self, max: Self) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let index = self.index(min.clone(), max.clone()).checked_sub(n)?;
+        Ix::from_index_checked(index, min, max)
+    }
+    /// Round the position of a value inside a range down to a multiple of `k`,
+    /// returning the element at the rounded position.
+    /// For composite types, this rounds the linear position, not each component.
+    ///
+    /// The default implementation uses [`index`] and [`from_index`],
+    /// but the implementations for built-in numeric types always return the exact element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(13i32.align_down(0, 31, 8), 8);
+    /// assert_eq!(13i32.align_down(1, 31, 8), 9);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`from_index`]: Ix::from_index
+    fn align_down(self, min: Self, max: Self, k: usize) -> Self
+    where
+        Self: Clone,
+    {
+        if k == 0 {
+            panic!("alignment is zero");
+        }
+        let index = self.index(min.clone(), max.clone());
+        Ix::from_index(index / k * k, min, max)
+    }
+    /// Round the position of a value inside a range up to a multiple of `k`,
+    /// returning the element at the rounded position, or `max` if the rounded position is past the end of the range.
+    /// For composite types, this rounds the linear position, not each component.
+    ///
+    /// The default implementation uses [`index`] and [`from_index`],
+    /// but the implementations for built-in numeric types always return the exact element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(13i32.align_up(0, 31, 8), 16);
+    /// assert_eq!(29i32.align_up(0, 31, 8), 31);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`from_index`]: Ix::from_index
+    fn align_up(self, min: Self, max: Self, k: usize) -> Self
+    where
+        Self: Clone,
+    {
+        if k == 0 {
+            panic!("alignment is zero");
+        }
+        let index = self.index(min.clone(), max.clone());
+        index
+            .div_ceil(k)
+            .checked_mul(k)
+            .and_then(|index| Ix::from_index_checked(index, min, max.clone()))
+            .unwrap_or(max)
+    }
+    /// Check whether the position of a value inside a range is a multiple of `k`,
+    /// which means that [`align_down`] and [`align_up`] would return the value itself.
+    /// For composite types, this checks the linear position, not each component.
+    ///
+    /// The default implementation uses [`index_u128`], so it works for positions that overflow [`usize`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(16i32.is_aligned(0, 31, 8));
+    /// assert!(!13i32.is_aligned(0, 31, 8));
+    /// assert!(9i32.is_aligned(1, 31, 8));
+    /// ```
+    ///
+    /// [`align_down`]: Ix::align_down
+    /// [`align_up`]: Ix::align_up
+    /// [`index_u128`]: Ix::index_u128
+    #[allow(clippy::wrong_self_convention)]
+    fn is_aligned(self, min: Self, max: Self, k: usize) -> bool {
+        if k == 0 {
+            panic!("alignment is zero");
+        }
+        self.index_u128(min, max).is_multiple_of(k as u128)
+    }
+    /// Check whether a range has exactly one element, without computing its size.
+    /// For composite types, this means that every component has exactly one element.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::is_single(5i32, 5));
+    /// assert!(!Ix::is_single((0u8, 0u8), (0, 1)));
+    /// ```
+    fn is_single(min: Self, max: Self) -> bool {
+        assert_ordered!(min, max);
+        min == max
+    }
+    /// Check whether a range has more than one element, without computing its size.
+    /// This is the negation of [`is_single`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::is_multi(0u128, u128::MAX));
+    /// assert!(!Ix::is_multi('a', 'a'));
+    /// ```
+    ///
+    /// [`is_single`]: Ix::is_single
+    fn is_multi(min: Self, max: Self) -> bool {
+        !Ix::is_single(min, max)
+    }
+    /// Get the linear extent of a range,
+    /// which is the position of `max` inside the range (one less than the size of the range).
+    ///
+    /// The default implementation panics if the extent is not representable as a [`usize`] value,
+    /// but the implementations for built-in numeric types always return the exact extent.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::extent_u128(3i32, 8), 5);
+    /// assert_eq!(Ix::extent_u128(0u8, 255), 255);
+    /// assert_eq!(Ix::extent_u128(i128::MIN, i128::MAX), u128::MAX);
+    /// ```
+    fn extent_u128(min: Self, max: Self) -> u128 {
+        (Ix::range_size(min, max) - 1) as u128
+    }
+    /// Get the position of a value inside a range as a [`u128`] value.
+    ///
+    /// The default implementation panics if the position is not representable as a [`usize`] value,
+    /// but the implementations for built-in numeric types always return the exact position.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(5i32.index_u128(3, 8), 2);
+    /// assert_eq!(u128::MAX.index_u128(0, u128::MAX), u128::MAX);
+    /// assert_eq!(0i128.index_u128(i128::MIN, i128::MAX), 1 << 127);
+    /// ```
+    fn index_u128(self, min: Self, max: Self) -> u128 {
+        self.index(min, max) as u128
+    }
+    /// Get the position of a value inside a range modulo `table_size`,
+    /// for example to pick a slot of a hash table.
+    ///
+    /// The default implementation uses [`index_u128`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table_size` is zero.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(13i32.index_mod(10, 30, 4), 3);
+    /// assert_eq!(u128::MAX.index_mod(0, u128::MAX, 10), 5);
+    /// ```
+    ///
+    /// [`index_u128`]: Ix::index_u128
+    fn index_mod(self, min: Self, max: Self, table_size: usize) -> usize {
+        if table_size == 0 {
+            panic!("table size is zero");
+        }
+        (self.index_u128(min, max) % table_size as u128) as usize
+    }
+    /// Get the position of a value inside a range as 16 big-endian bytes,
+    /// which is the same on every platform.
+    /// This is the big-endian representation of [`index_u128`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let bytes = 300i32.index_be_bytes(0, 1000);
+    /// assert_eq!(bytes[14..], [1, 44]);
+    /// assert_eq!(Ix::from_index_be_bytes(bytes, 0i32, 1000), Some(300));
+    /// ```
+    ///
+    /// [`index_u128`]: Ix::index_u128
+    fn index_be_bytes(self, min: Self, max: Self) -> [u8; 16] {
+        self.index_u128(min, max).to_be_bytes()
+    }
+    /// Get the element at a position inside a range, given as 16 big-endian bytes,
+    /// undoing [`index_be_bytes`].
+    ///
+    /// Returns [`None`] if the position is not less than the size of the range.
+    /// The default implementation also returns [`None`] if the position is not representable as a [`usize`] value,
+    /// but the implementations for built-in numeric types accept every position.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let bytes = u128::MAX.index_be_bytes(0, u128::MAX);
+    /// assert_eq!(bytes, [0xFF; 16]);
+    /// assert_eq!(Ix::from_index_be_bytes(bytes, 0u128, u128::MAX), Some(u128::MAX));
+    /// assert_eq!(Ix::from_index_be_bytes(bytes, 0u128, 5), None);
+    /// ```
+    ///
+    /// [`index_be_bytes`]: Ix::index_be_bytes
+    fn from_index_be_bytes(bytes: [u8; 16], min: Self, max: Self) -> Option<Self> {
+        let index = usize::try_from(u128::from_be_bytes(bytes)).ok()?;
+        Ix::from_index_checked(index, min, max)
+    }
+    /// Get the number of positions by which one value is ahead of another inside a range,
+    /// which is negative if `self` comes before `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the difference is not representable as an [`i128`] value.
+    /// The default implementation does this by unwrapping the return value of [`checked_index_diff`].
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` or `other` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(3i32.index_diff(7, 0, 10), -4);
+    /// assert_eq!(7i32.index_diff(3, 0, 10), 4);
+    /// ```
+    ///
+    /// [`checked_index_diff`]: Ix::checked_index_diff
+    fn index_diff(self, other: Self, min: Self, max: Self) -> i128
+    where
+        Self: Clone,
+    {
+        self.checked_index_diff(other, min, max)
+            .expect("difference too large")
+    }
+    /// Get the number of positions by which one value is ahead of another inside a range,
+    /// which is negative if `self` comes before `other`.
+    ///
+    /// Returns [`None`] if the difference is not representable as an [`i128`] value.
+    ///
+    /// The default implementation uses [`index_u128`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` or `other` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!((0u8, 2u8).checked_index_diff((1, 0), (0, 0), (3, 3)), Some(-2));
+    /// assert_eq!(u128::MAX.checked_index_diff(0, 0, u128::MAX), None);
+    /// ```
+    ///
+    /// [`index_u128`]: Ix::index_u128
+    fn checked_index_diff(self, other: Self, min: Self, max: Self) -> Option<i128>
+    where
+        Self: Clone,
+    {
+        let a = self.index_u128(min.clone(), max.clone());
+        let b = other.index_u128(min, max);
+        if a >= b {
+            i128::try_from(a - b).ok()
+        } else {
+            i128::try_from(b - a - 1).ok().map(|d| -d - 1)
+        }
+    }
+    /// Generate an iterator over `n` evenly spaced positions inside a range, as [`f64`] values,
+    /// starting from the position of `min` (`0.0`) and stopping at the position of `max`.
+    /// If `n` is zero, the iterator is empty, and if `n` is one, it only produces `0.0`.
+    ///
+    /// The positions are those of [`index_u128`], so for numeric types they are offsets from `min`;
+    /// [`NumericIx::linspace`] produces the values themselves instead.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::linspace_positions(5i32, 15, 6).eq([0.0, 2.0, 4.0, 6.0, 8.0, 10.0]));
+    /// assert!(Ix::linspace_positions('a', 'e', 3).eq([0.0, 2.0, 4.0]));
+    /// assert!(Ix::linspace_positions(5i32, 15, 1).eq([0.0]));
+    /// ```
+    ///
+    /// [`index_u128`]: Ix::index_u128
+    fn linspace_positions(min: Self, max: Self, n: usize) -> Linspace {
+        Linspace::new(0.0, Ix::extent_u128(min, max) as f64, n)
+    }
+    /// Compare two values by their positions inside a range.
+    /// For well-behaved implementations, this agrees with [`PartialOrd`] for values in the range.
+    ///
+    /// The default implementation uses [`index_u128`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` or `other` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// # use ix_rs::Ix;
+    /// assert_eq!(3i32.cmp_by_index(7, 0, 9), Ordering::Less);
+    /// assert_eq!((1u8, 0u8).cmp_by_index((0, 2), (0, 0), (2, 2)), Ordering::Greater);
+    /// ```
+    ///
+    /// [`index_u128`]: Ix::index_u128
+    fn cmp_by_index(self, other: Self, min: Self, max: Self) -> core::cmp::Ordering
+    where
+        Self: Clone,
+    {
+        let a = self.index_u128(min.clone(), max.clone());
+        a.cmp(&other.index_u128(min, max))
+    }
+    /// Get the position of a value inside a range, scaled to lie between `0.0` and `1.0`,
+    /// such that `min` maps to `0.0` and `max` maps to `1.0`.
+    /// For a range with a single element, returns `0.0`.
+    ///
+    /// The default implementation uses [`index_u128`] and [`extent_u128`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(0i32.span_ratio(0, 4), 0.0);
+    /// assert_eq!(2i32.span_ratio(0, 4), 0.5);
+    /// assert_eq!(4i32.span_ratio(0, 4), 1.0);
+    /// assert_eq!('x'.span_ratio('x', 'x'), 0.0);
+    /// ```
+    ///
+    /// [`index_u128`]: Ix::index_u128
+    /// [`extent_u128`]: Ix::extent_u128
+    fn span_ratio(self, min: Self, max: Self) -> f64
+    where
+        Self: Clone,
+    {
+        let extent = Ix::extent_u128(min.clone(), max.clone());
+        let index = self.index_u128(min, max);
+        if extent == 0 {
+            0.0
+        } else {
+            index as f64 / extent as f64
+        }
+    }
+    /// Get the Manhattan distance between two values inside a range.
+    /// For composite types, this is the sum of the distances along each component.
+    /// For other types, this is the difference between the positions of the values inside the range.
+    ///
+    /// The default implementation uses [`index`],
+    /// but the implementations for built-in numeric types always return the exact distance.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if either value is not in the range (as determined by [`in_range`]).
+    ///
+    /// Panics if the distance is not representable as a [`u128`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!((0i32, 0i32).manhattan_distance((2, 3), (0, 0), (9, 9)), 5);
+    /// assert_eq!((2i32, 3i32).manhattan_distance((0, 0), (0, 0), (9, 9)), 5);
+    /// assert_eq!(u128::MAX.manhattan_distance(0, 0, u128::MAX), u128::MAX);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`in_range`]: Ix::in_range
+    fn manhattan_distance(self, other: Self, min: Self, max: Self) -> u128
+    where
+        Self: Clone,
+    {
+        let a = self.index(min.clone(), max.clone()) as u128;
+        let b = other.index(min, max) as u128;
+        a.abs_diff(b)
+    }
+    /// Get the rank of a value inside a range,
+    /// which is the number of elements of the range that precede it.
+    /// Equivalent to [`index`], and the inverse of [`select`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// Panics if the rank is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(15u32.rank(10, 20), 5);
+    /// assert_eq!(Ix::select(15u32.rank(10, 20), 10, 20), 15);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`select`]: Ix::select
+    /// [`in_range`]: Ix::in_range
+    fn rank(self, min: Self, max: Self) -> usize {
+        self.index(min, max)
+    }
+    /// Get the element of a range with the given rank,
+    /// which is the element preceded by exactly `rank` elements of the range.
+    /// Equivalent to [`from_index`], and the inverse of [`rank`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if `rank` is not less than the size of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::select(0, -3i8, 3), -3);
+    /// assert_eq!(Ix::select(4, -3i8, 3).rank(-3, 3), 4);
+    /// ```
+    ///
+    /// [`from_index`]: Ix::from_index
+    /// [`rank`]: Ix::rank
+    fn select(rank: usize, min: Self, max: Self) -> Self {
+        Ix::from_index(rank, min, max)
+    }
+    /// Count the elements of a range from `lo` to `hi` inclusive,
+    /// which is the difference of their ranks plus one.
+    /// If `lo` comes after `hi`, returns `0`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `lo` or `hi` is not in the range (as determined by [`in_range`]).
+    ///
+    /// Panics if either rank is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::count_between(12u32, 15, 10, 20), 4);
+    /// assert_eq!(Ix::count_between(15u32, 15, 10, 20), 1);
+    /// assert_eq!(Ix::count_between(15u32, 12, 10, 20), 0);
+    /// ```
+    ///
+    /// [`in_range`]: Ix::in_range
+    fn count_between(lo: Self, hi: Self, min: Self, max: Self) -> usize
+    where
+        Self: Clone,
+    {
+        let lo = lo.rank(min.clone(), max.clone());
+        let hi = hi.rank(min, max);
+        if lo > hi {
+            0
+        } else {
+            hi - lo + 1
+        }
+    }
+    /// Get which of `buckets` equally sized buckets a value inside a range falls into.
+    /// The buckets partition the range in order, so `min` falls into the first bucket
+    /// and `max` falls into the last bucket.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buckets` is zero.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if the value is not in the range (as determined by [`in_range`]).
+    ///
+    /// Panics if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(0i32.quantize(0, 99, 10), 0);
+    /// assert_eq!(9i32.quantize(0, 99, 10), 0);
+    /// assert_eq!(10i32.quantize(0, 99, 10), 1);
+    /// assert_eq!(99i32.quantize(0, 99, 10), 9);
+    /// ```
+    ///
+    /// [`in_range`]: Ix::in_range
+    fn quantize(self, min: Self, max: Self, buckets: usize) -> usize
+    where
+        Self: Clone,
+    {
+        if buckets == 0 {
+            panic!("number of buckets is zero");
+        }
+        let size = Ix::range_size(min.clone(), max.clone()) as u128;
+        let index = self.index(min, max) as u128;
+        (index * buckets as u128 / size) as usize
+    }
+    /// Map a value inside a source range to the proportionally corresponding element of a destination range,
+    /// which may be of another type.
+    /// `src_min` maps to `dst_min` and `src_max` maps to `dst_max`,
+    /// and positions in between are scaled and rounded to the nearest position, with ties rounding up.
+    /// If the source range has a single element, it maps to `dst_min`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `src_min` is greater than `src_max` or `dst_min` is greater than `dst_max`.
+    ///
+    /// Should panic if the value is not in the source range (as determined by [`in_range`]).
+    ///
+    /// Panics if the size of either range is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(0i32.remap(0, 100, 0u8, 255), 0);
+    /// assert_eq!(25i32.remap(0, 100, 0u8, 255), 64);
+    /// assert_eq!(100i32.remap(0, 100, 0u8, 255), 255);
+    /// assert_eq!(3u8.remap(0, 4, 'a', 'c'), 'c');
+    /// ```
+    ///
+    /// [`in_range`]: Ix::in_range
+    fn remap<O: Ix + Clone>(self, src_min: Self, src_max: Self, dst_min: O, dst_max: O) -> O
+    where
+        Self: Clone,
+    {
+        let src_extent = Ix::range_size(src_min.clone(), src_max.clone()) as u128 - 1;
+        let dst_extent = Ix::range_size(dst_min.clone(), dst_max.clone()) as u128 - 1;
+        let index = self.index(src_min, src_max) as u128;
+        if src_extent == 0 {
+            return dst_min;
+        }
+        // Both extents are below `usize::MAX`, so the product cannot overflow.
+        let scaled = index * dst_extent;
+        let (quotient, remainder) = (scaled / src_extent, scaled % src_extent);
+        let index = if remainder >= src_extent - remainder {
+            quotient + 1
+        } else {
+            quotient
+        };
+        Ix::from_index(index as usize, dst_min, dst_max)
+    }
+    /// Map a value inside an output range to the corresponding element of a source range
+    /// that is repeated to fill the output range.
+    /// This is the element of the source range at the position of `self` in the output range,
+    /// wrapped around the size of the source range.
+    ///
+    /// The default implementation uses [`index`] and [`from_index`],
+    /// but the implementations for built-in numeric types always return the exact element.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `out_min` is greater than `out_max` or `src_min` is greater than `src_max`.
+    ///
+    /// Should panic if `self` is not in the output range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::range(0i32, 9).map(|x| x.tiled_lookup(0, 9, 0, 2)).eq([0, 1, 2, 0, 1, 2, 0, 1, 2, 0]));
+    /// assert_eq!(7u8.tiled_lookup(5, 10, 100, 101), 100);
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`from_index`]: Ix::from_index
+    fn tiled_lookup(self, out_min: Self, out_max: Self, src_min: Self, src_max: Self) -> Self
+    where
+        Self: Clone,
+    {
+        let size = Ix::range_size(src_min.clone(), src_max.clone());
+        Ix::from_index(self.index(out_min, out_max) % size, src_min, src_max)
+    }
+    /// Map a value inside an output range to the corresponding element of a source range
+    /// that is repeated to fill the output range, with every other repetition mirrored.
+    /// For a source range of three elements, successive values of the output range map to the positions
+    /// `0, 1, 2, 2, 1, 0, 0, 1, 2, ...` of the source range.
+    ///
+    /// The default implementation uses [`index`] and [`from_index`],
+    /// but the implementations for built-in numeric types always return the exact element.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `out_min` is greater than `out_max` or `src_min` is greater than `src_max`.
+    ///
+    /// Should panic if `self` is not in the output range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::range(0i32, 7).map(|x| x.mirrored_lookup(0, 7, 0, 2)).eq([0, 1, 2, 2, 1, 0, 0, 1]));
+    /// ```
+    ///
+    /// [`index`]: Ix::index
+    /// [`from_index`]: Ix::from_index
+    fn mirrored_lookup(self, out_min: Self, out_max: Self, src_min: Self, src_max: Self) -> Self
+    where
+        Self: Clone,
+    {
+        let size = Ix::range_size(src_min.clone(), src_max.clone());
+        let offset = self.index(out_min, out_max);
+        let offset = match size.checked_mul(2) {
+            Some(period) => offset % period,
+            None => offset,
+        };
+        let index = if offset < size {
+            offset
+        } else {
+            size - 1 - (offset - size)
+        };
+        Ix::from_index(index, src_min, src_max)
+    }
+    /// Get the first element of a bucket, as determined by [`quantize`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket` is not less than `buckets`, or if the bucket is empty,
+    /// which is only possible if there are more buckets than elements in the range.
+    /// The default implementation does this by unwrapping the return value of [`bucket_start_checked`].
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::bucket_start(3, 0i32, 99, 10), 30);
+    /// assert_eq!(Ix::bucket_start(3, 0i32, 99, 10).quantize(0, 99, 10), 3);
+    /// ```
+    ///
+    /// [`quantize`]: Ix::quantize
+    /// [`bucket_start_checked`]: Ix::bucket_start_checked
+    fn bucket_start(bucket: usize, min: Self, max: Self, buckets: usize) -> Self
+    where
+        Self: Clone,
+    {
+        Ix::bucket_start_checked(bucket, min, max, buckets).expect("bucket out of range or empty")
+    }
+    /// Get the first element of a bucket, as determined by [`quantize`].
+    /// If `bucket` is not less than `buckets`, or if the bucket is empty, returns [`None`].
+    /// Checked version of [`bucket_start`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::bucket_start_checked(1, 0i32, 1, 4), None);
+    /// assert_eq!(Ix::bucket_start_checked(2, 0i32, 1, 4), Some(1));
+    /// assert_eq!(Ix::bucket_start_checked(4, 0i32, 1, 4), None);
+    /// ```
+    ///
+    /// [`quantize`]: Ix::quantize
+    /// [`bucket_start`]: Ix::bucket_start
+    fn bucket_start_checked(bucket: usize, min: Self, max: Self, buckets: usize) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let size = Ix::range_size(min.clone(), max.clone());
+        let (first, _) = bucket_indices(bucket, size, buckets)?;
+        Ix::from_index_checked(first, min, max)
+    }
+    /// Get the middle element of a bucket, as determined by [`quantize`].
+    /// If the bucket has an even number of elements, this is the earlier of the two middle elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket` is not less than `buckets`, or if the bucket is empty,
+    /// which is only possible if there are more buckets than elements in the range.
+    /// The default implementation does this by unwrapping the return value of [`bucket_midpoint_checked`].
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert_eq!(Ix::bucket_midpoint(3, 0i32, 99, 10), 34);
+    /// assert_eq!(Ix::bucket_midpoint(0, 0i32, 8, 3), 1);
+    /// ```
+    ///
+    /// [`quantize`]: Ix::quantize
+    /// [`bucket_midpoint_checked`]: Ix::bucket_midpoint_checked
+    fn bucket_midpoint(bucket: usize, min: Self, max: Self, buckets: usize) -> Self
+    where
+        Self: Clone,
+    {
+        Ix::bucket_midpoint_checked(bucket, min, max, buckets)
+            .expect("bucket out of range or empty")
+    }
+    /// Get the middle element of a bucket, as determined by [`quantize`].
+    /// If `bucket` is not less than `buckets`, or if the bucket is empty, returns [`None`].
+    /// Checked version of [`bucket_midpoint`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// [`quantize`]: Ix::quantize
+    /// [`bucket_midpoint`]: Ix::bucket_midpoint
+    fn bucket_midpoint_checked(bucket: usize, min: Self, max: Self, buckets: usize) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let size = Ix::range_size(min.clone(), max.clone());
+        let (first, last) = bucket_indices(bucket, size, buckets)?;
+        Ix::from_index_checked(first + (last - first) / 2, min, max)
+    }
+    /// Generate an iterator over the bounds of overlapping chunks of a range starting from `min` and stopping at `max`.
+    /// Each item is a pair of the first and the last element of a chunk of `size` elements,
+    /// and each chunk starts `size - overlap` elements after the previous one.
+    /// The last chunk is the first one to reach `max`, and it is clipped to the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `overlap` is not less than `size`.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::overlapping_chunks(0i32, 9, 4, 1).eq([(0, 3), (3, 6), (6, 9)]));
+    /// assert!(Ix::overlapping_chunks(0i32, 4, 2, 0).eq([(0, 1), (2, 3), (4, 4)]));
+    /// ```
+    fn overlapping_chunks(
+        min: Self,
+        max: Self,
+        size: usize,
+        overlap: usize,
+    ) -> OverlappingChunks<Self>
+    where
+        Self: Clone,
+    {
+        OverlappingChunks::new(min, max, size, overlap)
+    }
+    /// Split a range starting from `min` and stopping at `max` into chunks of exactly `size` elements.
+    /// Returns an iterator over the bounds of the chunks,
+    /// each being a pair of the first and the last element of a chunk,
+    /// together with the bounds of the leftover elements at the end of the range,
+    /// or [`None`] if the size of the range is a multiple of `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// let (chunks, remainder) = Ix::chunks_exact(0i32, 9, 4);
+    /// assert!(chunks.eq([(0, 3), (4, 7)]));
+    /// assert_eq!(remainder, Some((8, 9)));
+    /// let (chunks, remainder) = Ix::chunks_exact(0i32, 7, 4);
+    /// assert!(chunks.eq([(0, 3), (4, 7)]));
+    /// assert_eq!(remainder, None);
+    /// ```
+    fn chunks_exact(min: Self, max: Self, size: usize) -> (ChunksExact<Self>, Option<(Self, Self)>)
+    where
+        Self: Clone,
+    {
+        ChunksExact::new(min, max, size)
+    }
+    /// Generate an iterator over the bounds of the buckets of a range, as determined by [`quantize`].
+    /// Each item is a pair of the first and the last element of a bucket.
+    /// The buckets are produced in order and together cover the range exactly,
+    /// with sizes differing by at most one.
+    ///
+    /// If there are more buckets than elements in the range, the empty buckets are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buckets` is zero.
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if the size of the range is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::Ix;
+    /// assert!(Ix::histogram_bins(0u8, 9, 3).eq([(0, 3), (4, 6), (7, 9)]));
+    /// assert!(Ix::histogram_bins(0u8, 1, 4).eq([(0, 0), (1, 1)]));
+    /// ```
+    ///
+    /// [`quantize`]: Ix::quantize
+    fn histogram_bins(min: Self, max: Self, buckets: usize) -> HistogramBins<Self>
+    where
+        Self: Clone,
+    {
+        HistogramBins::new(min, max, buckets)
+    }
+}
+
+/// A trait for [`Ix`] types that have a least and a greatest value.
+///
+/// Implementations must uphold the following invariants:
+/// 1. `BoundedIx::MIN <= ix` for every value `ix`
+/// 2. `ix <= BoundedIx::MAX` for every value `ix`
+///
+/// # Examples
+///
+/// ```
+/// # use ix_rs::BoundedIx;
+/// assert!(i8::range_from(125).eq([125, 126, 127]));
+/// ```
+/// ```
+/// # use ix_rs::BoundedIx;
+/// assert!(u8::range_to(2).eq([0, 1, 2]));
+/// ```
+pub trait BoundedIx: Ix {
+    /// The least value of the implementing type.
+    const MIN: Self;
+    /// The greatest value of the implementing type.
+    const MAX: Self;
+    /// Generate an iterator over a range starting from `min` and stopping at [`MAX`].
+    ///
+    /// [`MAX`]: BoundedIx::MAX
+    fn range_from(min: Self) -> Self::Range {
+        Ix::range(min, Self::MAX)
+    }
+    /// Generate an iterator over a range starting from [`MIN`] and stopping at `max`.
+    ///
+    /// [`MIN`]: BoundedIx::MIN
+    fn range_to(max: Self) -> Self::Range {
+        Ix::range(Self::MIN, max)
+    }
+    /// Generate an iterator over every value of the implementing type,
+    /// starting from [`MIN`] and stopping at [`MAX`].
+    ///
+    /// The iterator is lazy, but note that for types with more values than fit in a [`usize`]
+    /// the size of the full range is not representable,
+    /// so [`range_size`] panics and counting the iterator's elements overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::BoundedIx;
+    /// assert_eq!(u8::full_range().count(), 256);
+    /// ```
+    ///
+    /// [`MIN`]: BoundedIx::MIN
+    /// [`MAX`]: BoundedIx::MAX
+    /// [`range_size`]: Ix::range_size
+    fn full_range() -> Self::Range {
+        Ix::range(Self::MIN, Self::MAX)
+    }
+    /// Expand a range by `n` elements on each side.
+    /// The resulting bounds are clamped to [`MIN`] and [`MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::BoundedIx;
+    /// assert_eq!(BoundedIx::pad_bounds(2i32, 8, 5), (-3, 13));
+    /// assert_eq!(BoundedIx::pad_bounds(i32::MIN + 2, 0, 5), (i32::MIN, 5));
+    /// ```
+    ///
+    /// [`MIN`]: BoundedIx::MIN
+    /// [`MAX`]: BoundedIx::MAX
+    fn pad_bounds(min: Self, max: Self, n: usize) -> (Self, Self);
+    /// Contract a range by `n` elements on each side.
+    /// If this would leave no elements in the range, returns [`None`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::BoundedIx;
+    /// assert_eq!(BoundedIx::shrink_bounds(0i32, 10, 5), Some((5, 5)));
+    /// assert_eq!(BoundedIx::shrink_bounds(0i32, 10, 6), None);
+    /// ```
+    fn shrink_bounds(min: Self, max: Self, n: usize) -> Option<(Self, Self)>;
+    /// Map a value inside a range starting from `min` and stopping at `max`
+    /// to the value at the same position inside a range starting from `to_min`.
+    /// For composite types, each component is mapped separately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting value would be greater than [`MAX`].
+    /// The default implementation does this by unwrapping the return value of [`reindex_checked`].
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::BoundedIx;
+    /// assert_eq!(5i32.reindex(3, 7, 100), 102);
+    /// assert_eq!((1u8, 5u8).reindex((0, 4), (2, 6), (10, 20)), (11, 21));
+    /// ```
+    ///
+    /// [`MAX`]: BoundedIx::MAX
+    /// [`reindex_checked`]: BoundedIx::reindex_checked
+    fn reindex(self, min: Self, max: Self, to_min: Self) -> Self {
+        self.reindex_checked(min, max, to_min)
+            .expect("reindexed value is out of bounds")
+    }
+    /// Map a value inside a range starting from `min` and stopping at `max`
+    /// to the value at the same position inside a range starting from `to_min`.
+    /// For composite types, each component is mapped separately.
+    ///
+    /// Returns [`None`] if the resulting value would be greater than [`MAX`].
+    /// The default implementation also returns [`None`]
+    /// if the position of `self` is not representable as a [`usize`] value.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Should panic if `self` is not in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::BoundedIx;
+    /// assert_eq!(5i32.reindex_checked(3, 7, 100), Some(102));
+    /// assert_eq!(5u8.reindex_checked(3, 7, 254), None);
+    /// ```
+    ///
+    /// [`MAX`]: BoundedIx::MAX
+    fn reindex_checked(self, min: Self, max: Self, to_min: Self) -> Option<Self> {
+        let index = self.index_checked(min, max)?;
+        Ix::from_index_checked(index, to_min, Self::MAX)
+    }
+}
+
+/// A trait for [`Ix`] types that are built-in integer types.
+pub trait NumericIx: BoundedIx {
+    /// Encode a value as its reflected binary Gray code.
+    /// The bit patterns of the Gray codes of two consecutive values differ in exactly one bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::NumericIx;
+    /// assert_eq!(6u8.to_gray(), 0b101);
+    /// assert_eq!(7u8.to_gray(), 0b100);
+    /// ```
+    fn to_gray(self) -> Self;
+    /// Decode a value from its reflected binary Gray code.
+    /// Inverse of [`to_gray`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::NumericIx;
+    /// assert_eq!(u8::from_gray(0b100), 7);
+    /// assert_eq!(i16::from_gray((-5).to_gray()), -5);
+    /// ```
+    ///
+    /// [`to_gray`]: NumericIx::to_gray
+    fn from_gray(gray: Self) -> Self;
+    /// Generate an iterator over the Gray codes of a range starting from `min` and stopping at `max`,
+    /// as determined by [`to_gray`].
+    /// The bit patterns of two consecutive elements differ in exactly one bit.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::NumericIx;
+    /// assert!(NumericIx::gray_range(0u8, 7).eq([0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]));
+    /// ```
+    ///
+    /// [`to_gray`]: NumericIx::to_gray
+    fn gray_range(min: Self, max: Self) -> core::iter::Map<Self::Range, fn(Self) -> Self> {
+        Ix::range(min, max).map(NumericIx::to_gray as fn(Self) -> Self)
+    }
+    /// Get the size of a hypercube with `dims` axes, each ranging from `min` to `max`,
+    /// which is the size of the range raised to the power of `dims`.
+    ///
+    /// Returns [`None`] if the size is not representable as a [`usize`] value.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::NumericIx;
+    /// assert_eq!(NumericIx::hypercube_size(0u8, 2, 4), Some(81));
+    /// assert_eq!(NumericIx::hypercube_size(0u8, 255, 0), Some(1));
+    /// assert_eq!(NumericIx::hypercube_size(0u32, 65535, 5), None);
+    /// ```
+    fn hypercube_size(min: Self, max: Self, dims: u32) -> Option<usize> {
+        Ix::range_size_checked(min, max)?.checked_pow(dims)
+    }
+    /// Get the sum of the elements of a range starting from `min` and stopping at `max`,
+    /// computed with the formula for arithmetic series rather than by iterating.
+    ///
+    /// Returns [`None`] if the sum is not representable as an [`i128`] value.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::NumericIx;
+    /// assert_eq!(NumericIx::sum_range(1u32, 100), Some(5050));
+    /// assert_eq!(NumericIx::sum_range(i64::MIN, i64::MAX), Some(i64::MIN.into()));
+    /// assert_eq!(NumericIx::sum_range(0u128, u128::MAX), None);
+    /// ```
+    fn sum_range(min: Self, max: Self) -> Option<i128>;
+    /// Get the product of the elements of a range starting from `min` and stopping at `max`.
+    ///
+    /// Returns [`None`] if the product is not representable as an [`i128`] value.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::NumericIx;
+    /// assert_eq!(NumericIx::product_range(1u8, 5), Some(120));
+    /// assert_eq!(NumericIx::product_range(-3i32, -1), Some(-6));
+    /// assert_eq!(NumericIx::product_range(i64::MIN, i64::MAX), Some(0));
+    /// assert_eq!(NumericIx::product_range(1u8, 255), None);
+    /// ```
+    fn product_range(min: Self, max: Self) -> Option<i128>;
+    /// Get the sum of the elements of a range starting from `min` and stopping at `max`,
+    /// computed with the formula for arithmetic series rather than by iterating.
+    /// Unlike [`sum_range`], this covers sums greater than [`i128::MAX`].
+    ///
+    /// Returns [`None`] if the sum is negative or not representable as a [`u128`] value.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::NumericIx;
+    /// assert_eq!(NumericIx::reduce_sum_u128(1u64, 1_000_000), Some(500_000_500_000));
+    /// assert_eq!(NumericIx::reduce_sum_u128(-3i8, 5), Some(9));
+    /// assert_eq!(NumericIx::reduce_sum_u128(-5i8, 3), None);
+    /// assert_eq!(NumericIx::reduce_sum_u128(u128::MAX, u128::MAX), Some(u128::MAX));
+    /// ```
+    ///
+    /// [`sum_range`]: NumericIx::sum_range
+    fn reduce_sum_u128(min: Self, max: Self) -> Option<u128>;
+    /// Generate an iterator over `n` evenly spaced values from `min` to `max` inclusive, as [`f64`] values,
+    /// like NumPy's `linspace`.
+    /// If `n` is zero, the iterator is empty, and if `n` is one, it only produces `min`.
+    /// The first and last values are exactly `min` and `max` converted to [`f64`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::NumericIx;
+    /// assert!(NumericIx::linspace(0i32, 10, 6).eq([0.0, 2.0, 4.0, 6.0, 8.0, 10.0]));
+    /// assert!(NumericIx::linspace(5i32, 15, 3).eq([5.0, 10.0, 15.0]));
+    /// assert!(NumericIx::linspace(-3i32, 7, 1).eq([-3.0]));
+    /// ```
+    fn linspace(min: Self, max: Self, n: usize) -> Linspace;
+}
+
+/// A trait for [`Ix`] types that are made up of components, such as tuples and arrays.
+pub trait CompositeIx: Ix {
+    /// The type holding one size per component,
+    /// which is a tuple of [`usize`] values for tuples and an array of [`usize`] values for arrays.
+    type AxisSizes;
+    /// Get the size of the range of each component, as determined by [`range_size`].
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if the size of the range of any component is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::CompositeIx;
+    /// assert_eq!(CompositeIx::axis_sizes((0u8, 'a'), (2, 'z')), (3, 26));
+    /// assert_eq!(CompositeIx::axis_sizes([0u8, 5], [9, 5]), [10, 1]);
+    /// ```
+    ///
+    /// [`range_size`]: Ix::range_size
+    fn axis_sizes(min: Self, max: Self) -> Self::AxisSizes;
+    /// Get the position along one axis of the value at a position inside a range,
+    /// without computing the other components.
+    /// This is the position of component number `axis` of [`from_index`]`(index, min, max)`
+    /// inside the range of that component.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `min` is greater than `max`.
+    ///
+    /// Panics if `axis` is not less than the number of components.
+    ///
+    /// Panics if `index` is not less than the size of the range.
+    ///
+    /// Panics if the size of the range of any component is not representable as a [`usize`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ix_rs::CompositeIx;
+    /// let (min, max) = ((0u8, 0u8), (9, 19));
+    /// assert_eq!(CompositeIx::axis_of(137, 0, min, max), 6);
+    /// assert_eq!(CompositeIx::axis_of(137, 1, min, max), 17);
+    /// ```
+    ///
+    /// [`from_index`]: Ix::from_index
+    fn axis_of(index: usize, axis: usize, min: Self, max: Self) -> usize;
+}
+
+/// Define a newtype wrapper around an [`Ix`] type whose [`Ix`] implementation forwards to the wrapped type.
+///
+/// Distinct newtypes defined this way can't be mixed up with each other,
+/// even if they wrap the same type.
+///
+/// # Examples
+///
+/// ```
+/// # use ix_rs::{define_ix_newtype, Ix};
+/// define_ix_newtype!(Row, u16);
+/// define_ix_newtype!(Col, u16);
+///
+/// assert_eq!(Row(5).index(Row(2), Row(10)), 3);
+/// assert!(Ix::range(Col(0), Col(2)).eq([Col(0), Col(1), Col(2)]));
+/// ```
+/// ```compile_fail
+/// # use ix_rs::{define_ix_newtype, Ix};
+/// define_ix_newtype!(Row, u16);
+/// define_ix_newtype!(Col, u16);
+///
+/// Row(5).index(Col(2), Col(10));
+/// ```
+#[macro_export]
+macro_rules! define_ix_newtype {
+    ($(#[$attr: meta])* $vis: vis $name: ident, $t: ty) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name(pub $t);
+
+        impl $crate::Ix for $name {
+            type Range = ::core::iter::Map<<$t as $crate::Ix>::Range, fn($t) -> $name>;
+            fn range(min: Self, max: Self) -> Self::Range {
+                <$t as $crate::Ix>::range(min.0, max.0).map($name as fn($t) -> $name)
+            }
+            fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+                <$t as $crate::Ix>::index_checked(self.0, min.0, max.0)
+            }
+            fn in_range(self, min: Self, max: Self) -> bool {
+                <$t as $crate::Ix>::in_range(self.0, min.0, max.0)
+            }
+            fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+                <$t as $crate::Ix>::range_size_checked(min.0, max.0)
+            }
+            fn extent_u128(min: Self, max: Self) -> u128 {
+                <$t as $crate::Ix>::extent_u128(min.0, max.0)
+            }
+            fn index_u128(self, min: Self, max: Self) -> u128 {
+                <$t as $crate::Ix>::index_u128(self.0, min.0, max.0)
+            }
+            fn from_index_be_bytes(bytes: [u8; 16], min: Self, max: Self) -> Option<Self> {
+                <$t as $crate::Ix>::from_index_be_bytes(bytes, min.0, max.0).map($name)
+            }
+            fn tiled_lookup(self, out_min: Self, out_max: Self, src_min: Self, src_max: Self) -> Self {
+                $name(<$t as $crate::Ix>::tiled_lookup(self.0, out_min.0, out_max.0, src_min.0, src_max.0))
+            }
+            fn mirrored_lookup(self, out_min: Self, out_max: Self, src_min: Self, src_max: Self) -> Self {
+                $name(<$t as $crate::Ix>::mirrored_lookup(self.0, out_min.0, out_max.0, src_min.0, src_max.0))
+            }
+            fn manhattan_distance(self, other: Self, min: Self, max: Self) -> u128 {
+                <$t as $crate::Ix>::manhattan_distance(self.0, other.0, min.0, max.0)
+            }
+            fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+                <$t as $crate::Ix>::from_index_checked(index, min.0, max.0).map($name)
+            }
+            fn successor(self, min: Self, max: Self) -> Option<Self> {
+                <$t as $crate::Ix>::successor(self.0, min.0, max.0).map($name)
+            }
+            fn predecessor(self, min: Self, max: Self) -> Option<Self> {
+                <$t as $crate::Ix>::predecessor(self.0, min.0, max.0).map($name)
+            }
+            fn advance(self, n: usize, min: Self, max: Self) -> Option<Self> {
+                <$t as $crate::Ix>::advance(self.0, n, min.0, max.0).map($name)
+            }
+            fn retreat(self, n: usize, min: Self, max: Self) -> Option<Self> {
+                <$t as $crate::Ix>::retreat(self.0, n, min.0, max.0).map($name)
+            }
+            fn align_down(self, min: Self, max: Self, k: usize) -> Self {
+                $name(<$t as $crate::Ix>::align_down(self.0, min.0, max.0, k))
+            }
+            fn align_up(self, min: Self, max: Self, k: usize) -> Self {
+                $name(<$t as $crate::Ix>::align_up(self.0, min.0, max.0, k))
+            }
+            fn merge(a: (Self, Self), b: (Self, Self)) -> Option<(Self, Self)> {
+                let (min, max) = <$t as $crate::Ix>::merge((a.0 .0, a.1 .0), (b.0 .0, b.1 .0))?;
+                Some(($name(min), $name(max)))
+            }
+            fn overlap_size_checked(a: (Self, Self), b: (Self, Self)) -> Option<usize> {
+                <$t as $crate::Ix>::overlap_size_checked((a.0 .0, a.1 .0), (b.0 .0, b.1 .0))
+            }
+            fn bounds_sorted(a: Self, b: Self) -> $crate::Bounds<Self> {
+                let (min, max) = <$t as $crate::Ix>::bounds_sorted(a.0, b.0).into_parts();
+                $crate::Bounds::new($name(min), $name(max))
+            }
+        }
+    };
+}
+
+/// Implement [`Ix`], [`PartialOrd`] and [`Ord`] for a fieldless enum,
+/// ordering its variants as they are listed.
+///
+/// The enum must implement [`Copy`] and [`Eq`], and every variant must be listed exactly once;
+/// leaving a variant out is a compile error.
+/// Its ranges are produced by iterating over a slice of all variants.
+///
+/// # Examples
+///
+/// ```
+/// # use ix_rs::{impl_ix_enum, Ix};
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// enum Dir {
+///     N,
+///     E,
+///     S,
+///     W,
+/// }
+///
+/// impl_ix_enum!(Dir { N, E, S, W });
+///
+/// assert!(Ix::range(Dir::E, Dir::W).eq([Dir::E, Dir::S, Dir::W]));
+/// assert_eq!(Dir::S.index(Dir::N, Dir::W), 2);
+/// assert!(Dir::N < Dir::W);
+/// ```
+///
+/// ```compile_fail
+/// # use ix_rs::impl_ix_enum;
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// enum Dir {
+///     N,
+///     E,
+///     S,
+///     W,
+/// }
+///
+/// impl_ix_enum!(Dir { N, E, S });
+/// ```
+#[macro_export]
+macro_rules! impl_ix_enum {
+    ($name: ident { $($variant: ident),+ $(,)? }) => {
+        const _: () = {
+            /// Mirrors the listed variants, so that casting gives their positions.
+            #[allow(non_camel_case_types)]
+            enum __IxPosition {
+                $($variant),+
+            }
+
+            const VARIANTS: &[$name] = &[$($name::$variant),+];
+
+            fn position(ix: &$name) -> usize {
+                match ix {
+                    $($name::$variant => __IxPosition::$variant as usize,)+
+                }
+            }
+
+            impl ::core::cmp::PartialOrd for $name {
+                fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                    Some(::core::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            impl ::core::cmp::Ord for $name {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    position(self).cmp(&position(other))
+                }
+            }
+
+            impl $crate::Ix for $name {
+                type Range = ::core::iter::Copied<::core::slice::Iter<'static, $name>>;
+                fn range(min: Self, max: Self) -> Self::Range {
+                    if min > max {
+                        panic!("min is greater than max");
+                    }
+                    VARIANTS[position(&min)..=position(&max)].iter().copied()
+                }
+                fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+                    if min > max {
+                        panic!("min is greater than max");
+                    }
+                    if self < min {
+                        panic!("index is outside range (< min)");
+                    } else if self > max {
+                        panic!("index is outside range (> max)");
+                    }
+                    Some(position(&self) - position(&min))
+                }
+                fn in_range(self, min: Self, max: Self) -> bool {
+                    if min > max {
+                        panic!("min is greater than max");
+                    }
+                    min <= self && self <= max
+                }
+                fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+                    if min > max {
+                        panic!("min is greater than max");
+                    }
+                    Some(position(&max) - position(&min) + 1)
+                }
+                fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+                    if min > max {
+                        panic!("min is greater than max");
+                    }
+                    let target = position(&min).checked_add(index)?;
+                    if target > position(&max) {
+                        None
+                    } else {
+                        Some(VARIANTS[target])
+                    }
+                }
+            }
+        };
+    };
+}
+
+/// Get the positions of the first and last element of a bucket, as determined by [`Ix::quantize`].
+fn bucket_indices(bucket: usize, size: usize, buckets: usize) -> Option<(usize, usize)> {
+    if bucket >= buckets {
+        return None;
+    }
+    let (size, buckets) = (size as u128, buckets as u128);
+    let start = |bucket: u128| (bucket * size).div_ceil(buckets);
+    let first = start(bucket as u128);
+    let end = start(bucket as u128 + 1);
+    if first >= end {
+        None
+    } else {
+        Some((first as usize, (end - 1) as usize))
+    }
+}
+
+/// Get the sum of the integers from `lo` to `hi` inclusive, or [`None`] if it overflows.
+fn sum_series_u128(lo: u128, hi: u128) -> Option<u128> {
+    let count = (hi - lo).checked_add(1)?;
+    if count.is_multiple_of(2) {
+        (count / 2).checked_mul(lo.checked_add(hi)?)
+    } else {
+        count.checked_mul(lo.checked_add(count / 2)?)
+    }
+}
+
+/// Get the sum of the integers from `min` to `max` inclusive,
+/// each given as a sign (`true` if negative) and a magnitude.
+/// Returns the sum in the same form, or [`None`] if its magnitude overflows.
+fn sum_series_signed(
+    (min_negative, min): (bool, u128),
+    (max_negative, max): (bool, u128),
+) -> Option<(bool, u128)> {
+    match (min_negative, max_negative) {
+        (false, _) => Some((false, sum_series_u128(min, max)?)),
+        (true, true) => Some((true, sum_series_u128(max, min)?)),
+        // The elements from `-min` to `min` or from `-max` to `max` cancel out.
+        (true, false) if min > max => Some((true, sum_series_u128(max + 1, min)?)),
+        (true, false) if min < max => Some((false, sum_series_u128(min + 1, max)?)),
+        (true, false) => Some((false, 0)),
+    }
+}
+
+macro_rules! distance {
+    ($min: expr, $max: expr) => {
+        ($max as u128).wrapping_sub($min as u128)
+    };
+}
+
+macro_rules! sign_magnitude {
+    ($ix: expr) => {
+        if $ix < 0 {
+            (true, i128::try_from($ix).ok()?.unsigned_abs())
+        } else {
+            (false, u128::try_from($ix).ok()?)
+        }
+    };
+}
+
+macro_rules! bits {
+    ($ix: expr) => {
+        ($ix as u128) & (u128::MAX >> (u128::BITS - <Self>::BITS))
+    };
+}
+
+macro_rules! impl_ix_numeric {
+    ($($t: ty),+) => {
+        $(
+            impl $crate::Ix for $t {
+                type Range = ::core::ops::RangeInclusive<$t>;
+                fn range(min: Self, max: Self) -> Self::Range {
+                    assert_ordered!(min, max);
+                    min..=max
+                }
+                fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    usize::try_from(distance!(min, self)).ok()
+                }
+                fn in_range(self, min: Self, max: Self) -> bool {
+                    assert_ordered!(min, max);
+                    min <= self && self <= max
+                }
+                fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+                    assert_ordered!(min, max);
+                    usize::try_from(distance!(min, max))
+                        .ok()
+                        .and_then(|n| n.checked_add(1))
+                }
+                fn extent_u128(min: Self, max: Self) -> u128 {
+                    assert_ordered!(min, max);
+                    distance!(min, max)
+                }
+                fn index_u128(self, min: Self, max: Self) -> u128 {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    distance!(min, self)
+                }
+                fn from_index_be_bytes(bytes: [u8; 16], min: Self, max: Self) -> Option<Self> {
+                    assert_ordered!(min, max);
+                    let index = u128::from_be_bytes(bytes);
+                    if index > distance!(min, max) {
+                        None
+                    } else {
+                        Some((min as u128).wrapping_add(index) as $t)
+                    }
+                }
+                fn tiled_lookup(self, out_min: Self, out_max: Self, src_min: Self, src_max: Self) -> Self {
+                    assert_ordered!(out_min, out_max);
+                    assert_ordered!(src_min, src_max);
+                    assert_in_range!(out_min, out_max, self);
+                    let offset = distance!(out_min, self);
+                    let offset = match distance!(src_min, src_max).checked_add(1) {
+                        Some(size) => offset % size,
+                        None => offset,
+                    };
+                    (src_min as u128).wrapping_add(offset) as $t
+                }
+                fn mirrored_lookup(self, out_min: Self, out_max: Self, src_min: Self, src_max: Self) -> Self {
+                    assert_ordered!(out_min, out_max);
+                    assert_ordered!(src_min, src_max);
+                    assert_in_range!(out_min, out_max, self);
+                    let offset = distance!(out_min, self);
+                    let extent = distance!(src_min, src_max);
+                    let offset = match extent.checked_add(1).and_then(|size| size.checked_mul(2)) {
+                        Some(period) => offset % period,
+                        None => offset,
+                    };
+                    let offset = if offset <= extent {
+                        offset
+                    } else {
+                        extent - (offset - extent - 1)
+                    };
+                    (src_min as u128).wrapping_add(offset) as $t
+                }
+                fn manhattan_distance(self, other: Self, min: Self, max: Self) -> u128 {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    assert_in_range!(min, max, other);
+                    if self < other {
+                        distance!(self, other)
+                    } else {
+                        distance!(other, self)
+                    }
+                }
+                fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+                    assert_ordered!(min, max);
+                    let index = index as u128;
+                    if index > distance!(min, max) {
+                        return None;
+                    }
+                    Some((min as u128).wrapping_add(index) as $t)
+                }
+                fn successor(self, min: Self, max: Self) -> Option<Self> {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    if self == max {
+                        None
+                    } else {
+                        Some(self + 1)
+                    }
+                }
+                fn predecessor(self, min: Self, max: Self) -> Option<Self> {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    if self == min {
+                        None
+                    } else {
+                        Some(self - 1)
+                    }
+                }
+                fn advance(self, n: usize, min: Self, max: Self) -> Option<Self> {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    let n = n as u128;
+                    if n > distance!(self, max) {
+                        return None;
+                    }
+                    Some((self as u128).wrapping_add(n) as $t)
+                }
+                fn retreat(self, n: usize, min: Self, max: Self) -> Option<Self> {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    let n = n as u128;
+                    if n > distance!(min, self) {
+                        return None;
+                    }
+                    Some((self as u128).wrapping_sub(n) as $t)
+                }
+                fn align_down(self, min: Self, max: Self, k: usize) -> Self {
+                    if k == 0 {
+                        panic!("alignment is zero");
+                    }
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    let offset = distance!(min, self);
+                    (min as u128).wrapping_add(offset - offset % k as u128) as $t
+                }
+                fn align_up(self, min: Self, max: Self, k: usize) -> Self {
+                    if k == 0 {
+                        panic!("alignment is zero");
+                    }
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    let offset = distance!(min, self);
+                    match offset.div_ceil(k as u128).checked_mul(k as u128) {
+                        Some(offset) if offset <= distance!(min, max) => {
+                            (min as u128).wrapping_add(offset) as $t
+                        }
+                        _ => max,
+                    }
+                }
+            }
+
+            impl $crate::BoundedIx for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+                fn pad_bounds(min: Self, max: Self, n: usize) -> (Self, Self) {
+                    assert_ordered!(min, max);
+                    let n = n as u128;
+                    let min = if n >= distance!(<$t>::MIN, min) {
+                        <$t>::MIN
+                    } else {
+                        (min as u128).wrapping_sub(n) as $t
+                    };
+                    let max = if n >= distance!(max, <$t>::MAX) {
+                        <$t>::MAX
+                    } else {
+                        (max as u128).wrapping_add(n) as $t
+                    };
+                    (min, max)
+                }
+                fn shrink_bounds(min: Self, max: Self, n: usize) -> Option<(Self, Self)> {
+                    assert_ordered!(min, max);
+                    let n = n as u128;
+                    if n > distance!(min, max) / 2 {
+                        return None;
+                    }
+                    Some((
+                        (min as u128).wrapping_add(n) as $t,
+                        (max as u128).wrapping_sub(n) as $t,
+                    ))
+                }
+                fn reindex_checked(self, min: Self, max: Self, to_min: Self) -> Option<Self> {
+                    assert_ordered!(min, max);
+                    assert_in_range!(min, max, self);
+                    let offset = distance!(min, self);
+                    if offset > distance!(to_min, <$t>::MAX) {
+                        None
+                    } else {
+                        Some((to_min as u128).wrapping_add(offset) as $t)
+                    }
+                }
+            }
+
+            impl $crate::NumericIx for $t {
+                fn to_gray(self) -> Self {
+                    let bits = bits!(self);
+                    (bits ^ (bits >> 1)) as $t
+                }
+                fn from_gray(gray: Self) -> Self {
+                    let mut bits = bits!(gray);
+                    let mut shift = 1;
+                    while shift < <$t>::BITS {
+                        bits ^= bits >> shift;
+                        shift *= 2;
+                    }
+                    bits as $t
+                }
+                #[allow(unused_comparisons)]
+                fn sum_range(min: Self, max: Self) -> Option<i128> {
+                    assert_ordered!(min, max);
+                    match sum_series_signed(sign_magnitude!(min), sign_magnitude!(max))? {
+                        (false, sum) => i128::try_from(sum).ok(),
+                        (true, sum) => 0i128.checked_sub_unsigned(sum),
+                    }
+                }
+                #[allow(unused_comparisons)]
+                fn reduce_sum_u128(min: Self, max: Self) -> Option<u128> {
+                    assert_ordered!(min, max);
+                    match sum_series_signed(sign_magnitude!(min), sign_magnitude!(max))? {
+                        (false, sum) => Some(sum),
+                        (true, _) => None,
+                    }
+                }
+                fn linspace(min: Self, max: Self, n: usize) -> Linspace {
+                    assert_ordered!(min, max);
+                    Linspace::new(min as f64, max as f64, n)
+                }
+                #[allow(unused_comparisons)]
+                fn product_range(min: Self, max: Self) -> Option<i128> {
+                    assert_ordered!(min, max);
+                    if min <= 0 && 0 <= max {
+                        return Some(0);
+                    }
+                    let mut product = 1i128;
+                    for x in min..=max {
+                        product = product.checked_mul(i128::try_from(x).ok()?)?;
+                    }
+                    Some(product)
+                }
+            }
+        )+
+    };
+}
+
+impl_ix_numeric!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize, isize);
+
+mod chars;
+mod nonzero;
+
+pub use chars::CharIx;
+
+#[cfg(feature = "num-bigint")]
+mod bigint;
+
+#[cfg(feature = "num-bigint")]
+pub use bigint::BigRange;
+
+#[cfg(feature = "time")]
+mod date;
+
+#[cfg(feature = "testing")]
+mod validate;
+
+#[cfg(feature = "testing")]
+pub use validate::{validate, PropertyViolation};
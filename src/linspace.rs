@@ -0,0 +1,46 @@
+/// An iterator over evenly spaced [`f64`] values between two endpoints, inclusive.
+///
+/// This struct is created by [`NumericIx::linspace`](crate::NumericIx::linspace)
+/// and [`Ix::linspace_positions`](crate::Ix::linspace_positions).
+#[derive(Clone, Debug)]
+pub struct Linspace {
+    next: usize,
+    count: usize,
+    start: f64,
+    end: f64,
+}
+
+impl Linspace {
+    pub(crate) fn new(start: f64, end: f64, count: usize) -> Self {
+        Linspace {
+            next: 0,
+            count,
+            start,
+            end,
+        }
+    }
+}
+
+impl Iterator for Linspace {
+    type Item = f64;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.count {
+            return None;
+        }
+        let k = self.next;
+        self.next += 1;
+        Some(if k == 0 {
+            self.start
+        } else if k == self.count - 1 {
+            self.end
+        } else {
+            self.start + (self.end - self.start) * k as f64 / (self.count - 1) as f64
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Linspace {}
@@ -0,0 +1,98 @@
+use core::{
+    iter::Map,
+    num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize},
+    ops::RangeInclusive,
+};
+
+/// Wrap a value known to be nonzero.
+macro_rules! nonzero {
+    ($nz: ty) => {
+        |n| <$nz>::new(n).expect("value is zero")
+    };
+}
+
+macro_rules! impl_ix_nonzero {
+    ($($nz: ty => $t: ty),+) => {
+        $(
+            impl crate::Ix for $nz {
+                type Range = Map<RangeInclusive<$t>, fn($t) -> $nz>;
+                fn range(min: Self, max: Self) -> Self::Range {
+                    <$t as crate::Ix>::range(min.get(), max.get()).map(nonzero!($nz) as fn($t) -> $nz)
+                }
+                fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+                    <$t as crate::Ix>::index_checked(self.get(), min.get(), max.get())
+                }
+                fn in_range(self, min: Self, max: Self) -> bool {
+                    <$t as crate::Ix>::in_range(self.get(), min.get(), max.get())
+                }
+                fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+                    <$t as crate::Ix>::range_size_checked(min.get(), max.get())
+                }
+                fn extent_u128(min: Self, max: Self) -> u128 {
+                    <$t as crate::Ix>::extent_u128(min.get(), max.get())
+                }
+                fn index_u128(self, min: Self, max: Self) -> u128 {
+                    <$t as crate::Ix>::index_u128(self.get(), min.get(), max.get())
+                }
+                fn tiled_lookup(self, out_min: Self, out_max: Self, src_min: Self, src_max: Self) -> Self {
+                    nonzero!($nz)(<$t as crate::Ix>::tiled_lookup(
+                        self.get(),
+                        out_min.get(),
+                        out_max.get(),
+                        src_min.get(),
+                        src_max.get(),
+                    ))
+                }
+                fn mirrored_lookup(self, out_min: Self, out_max: Self, src_min: Self, src_max: Self) -> Self {
+                    nonzero!($nz)(<$t as crate::Ix>::mirrored_lookup(
+                        self.get(),
+                        out_min.get(),
+                        out_max.get(),
+                        src_min.get(),
+                        src_max.get(),
+                    ))
+                }
+                fn manhattan_distance(self, other: Self, min: Self, max: Self) -> u128 {
+                    <$t as crate::Ix>::manhattan_distance(self.get(), other.get(), min.get(), max.get())
+                }
+                fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+                    <$t as crate::Ix>::from_index_checked(index, min.get(), max.get()).map(nonzero!($nz))
+                }
+                fn successor(self, min: Self, max: Self) -> Option<Self> {
+                    <$t as crate::Ix>::successor(self.get(), min.get(), max.get()).map(nonzero!($nz))
+                }
+                fn predecessor(self, min: Self, max: Self) -> Option<Self> {
+                    <$t as crate::Ix>::predecessor(self.get(), min.get(), max.get()).map(nonzero!($nz))
+                }
+                fn advance(self, n: usize, min: Self, max: Self) -> Option<Self> {
+                    <$t as crate::Ix>::advance(self.get(), n, min.get(), max.get()).map(nonzero!($nz))
+                }
+                fn retreat(self, n: usize, min: Self, max: Self) -> Option<Self> {
+                    <$t as crate::Ix>::retreat(self.get(), n, min.get(), max.get()).map(nonzero!($nz))
+                }
+            }
+
+            impl crate::BoundedIx for $nz {
+                const MIN: Self = <$nz>::MIN;
+                const MAX: Self = <$nz>::MAX;
+                fn pad_bounds(min: Self, max: Self, n: usize) -> (Self, Self) {
+                    let (min, max) = <$t as crate::BoundedIx>::pad_bounds(min.get(), max.get(), n);
+                    (<$nz>::new(min).unwrap_or(<$nz>::MIN), nonzero!($nz)(max))
+                }
+                fn shrink_bounds(min: Self, max: Self, n: usize) -> Option<(Self, Self)> {
+                    let (min, max) = <$t as crate::BoundedIx>::shrink_bounds(min.get(), max.get(), n)?;
+                    Some((nonzero!($nz)(min), nonzero!($nz)(max)))
+                }
+            }
+        )+
+    };
+}
+
+impl_ix_nonzero!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize
+);
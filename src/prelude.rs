@@ -0,0 +1,17 @@
+//! The traits and types that most users of this crate need.
+//!
+//! # Examples
+//!
+//! ```
+//! use ix_rs::prelude::*;
+//!
+//! let bounds = Bounds::new((0u8, 0u8), (2, 2));
+//! assert_eq!((1, 1).index_in(&bounds), 4);
+//! assert_eq!(Ix2::neighbors4((1, 1), *bounds.min(), *bounds.max()).count(), 4);
+//! assert!(u8::range_to(2).eq(0..=2));
+//! assert_eq!(5u8.to_gray(), 7);
+//! ```
+
+pub use crate::{
+    BoundedIx, Bounds, CharIx, CompositeIx, Ix, Ix2, NumericIx, OutOfRange, RangeError,
+};
@@ -0,0 +1,101 @@
+use crate::{BoundedIx, Bounds, Ix};
+use core::cmp::Reverse;
+
+/// An iterator over a range of [`Reverse`] values.
+///
+/// This is the [`Range`] type of the [`Ix`] implementation for [`Reverse`].
+/// It walks the underlying range backwards using [`predecessor`].
+///
+/// [`Range`]: Ix::Range
+/// [`predecessor`]: Ix::predecessor
+#[derive(Clone, Debug)]
+pub struct ReverseRange<T> {
+    next: Option<T>,
+    lo: T,
+    hi: T,
+}
+
+impl<T: Ix + Clone> Iterator for ReverseRange<T> {
+    type Item = Reverse<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        self.next = current
+            .clone()
+            .predecessor(self.lo.clone(), self.hi.clone());
+        Some(Reverse(current))
+    }
+}
+
+/// A range of [`Reverse`] values starting from `Reverse(hi)` and stopping at `Reverse(lo)`
+/// consists of the wrapped values from `hi` down to `lo`.
+impl<T: Ix + Clone> Ix for Reverse<T> {
+    type Range = ReverseRange<T>;
+    fn range(min: Self, max: Self) -> Self::Range {
+        assert_ordered!(min, max);
+        ReverseRange {
+            next: Some(min.0.clone()),
+            lo: max.0,
+            hi: min.0,
+        }
+    }
+    fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+        match T::range_size_checked(max.0.clone(), min.0.clone()) {
+            Some(size) => Some(size - 1 - self.0.index_checked(max.0, min.0)?),
+            None => usize::try_from(self.index_u128(min, max)).ok(),
+        }
+    }
+    fn in_range(self, min: Self, max: Self) -> bool {
+        self.0.in_range(max.0, min.0)
+    }
+    fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+        T::range_size_checked(max.0, min.0)
+    }
+    fn extent_u128(min: Self, max: Self) -> u128 {
+        T::extent_u128(max.0, min.0)
+    }
+    fn index_u128(self, min: Self, max: Self) -> u128 {
+        T::extent_u128(max.0.clone(), min.0.clone()) - self.0.index_u128(max.0, min.0)
+    }
+    fn manhattan_distance(self, other: Self, min: Self, max: Self) -> u128 {
+        self.0.manhattan_distance(other.0, max.0, min.0)
+    }
+    fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+        min.0.clone().retreat(index, max.0, min.0).map(Reverse)
+    }
+    fn successor(self, min: Self, max: Self) -> Option<Self> {
+        self.0.predecessor(max.0, min.0).map(Reverse)
+    }
+    fn predecessor(self, min: Self, max: Self) -> Option<Self> {
+        self.0.successor(max.0, min.0).map(Reverse)
+    }
+    fn advance(self, n: usize, min: Self, max: Self) -> Option<Self> {
+        self.0.retreat(n, max.0, min.0).map(Reverse)
+    }
+    fn retreat(self, n: usize, min: Self, max: Self) -> Option<Self> {
+        self.0.advance(n, max.0, min.0).map(Reverse)
+    }
+    fn merge(a: (Self, Self), b: (Self, Self)) -> Option<(Self, Self)> {
+        let (lo, hi) = T::merge((a.1 .0, a.0 .0), (b.1 .0, b.0 .0))?;
+        Some((Reverse(hi), Reverse(lo)))
+    }
+    fn overlap_size_checked(a: (Self, Self), b: (Self, Self)) -> Option<usize> {
+        T::overlap_size_checked((a.1 .0, a.0 .0), (b.1 .0, b.0 .0))
+    }
+    fn bounds_sorted(a: Self, b: Self) -> Bounds<Self> {
+        let (lo, hi) = T::bounds_sorted(a.0, b.0).into_parts();
+        Bounds::new(Reverse(hi), Reverse(lo))
+    }
+}
+
+impl<T: BoundedIx + Clone> BoundedIx for Reverse<T> {
+    const MIN: Self = Reverse(T::MAX);
+    const MAX: Self = Reverse(T::MIN);
+    fn pad_bounds(min: Self, max: Self, n: usize) -> (Self, Self) {
+        let (lo, hi) = T::pad_bounds(max.0, min.0, n);
+        (Reverse(hi), Reverse(lo))
+    }
+    fn shrink_bounds(min: Self, max: Self, n: usize) -> Option<(Self, Self)> {
+        let (lo, hi) = T::shrink_bounds(max.0, min.0, n)?;
+        Some((Reverse(hi), Reverse(lo)))
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{BoundedIx, Bounds, CompositeIx, Contains, Ix};
+
+/// An iterator over a range of tuples, in row-major order.
+///
+/// This is the [`Range`] type of the [`Ix`] implementations for tuples.
+/// The last component varies the fastest.
+///
+/// [`Range`]: Ix::Range
+#[derive(Clone, Debug)]
+pub struct TupleRange<T> {
+    next: Option<T>,
+    min: T,
+    max: T,
+}
+
+macro_rules! usize_for {
+    ($t: ident) => {
+        usize
+    };
+}
+
+macro_rules! impl_ix_tuple {
+    (($($t: ident $i: tt),*), ($($rt: ident $ri: tt),*)) => {
+        impl<$($t: Ix + Clone),*> Iterator for TupleRange<($($t,)*)> {
+            type Item = ($($t,)*);
+            #[allow(unused_mut, unused_variables)]
+            fn next(&mut self) -> Option<Self::Item> {
+                let current = self.next.take()?;
+                let mut next = current.clone();
+                $(
+                    match next.$ri.clone().successor(self.min.$ri.clone(), self.max.$ri.clone()) {
+                        Some(ix) => {
+                            next.$ri = ix;
+                            self.next = Some(next);
+                            return Some(current);
+                        }
+                        None => next.$ri = self.min.$ri.clone(),
+                    }
+                )*
+                Some(current)
+            }
+        }
+
+        impl<$($t: Ix + Clone),*> Ix for ($($t,)*) {
+            type Range = TupleRange<Self>;
+            fn range(min: Self, max: Self) -> Self::Range {
+                $(assert_ordered!(min.$i, max.$i);)*
+                TupleRange {
+                    next: Some(min.clone()),
+                    min,
+                    max,
+                }
+            }
+            #[allow(unused_variables)]
+            fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+                let index = 0usize;
+                $(
+                    let index = index
+                        .checked_mul($t::range_size_checked(min.$i.clone(), max.$i.clone())?)?
+                        .checked_add(self.$i.index_checked(min.$i, max.$i)?)?;
+                )*
+                Some(index)
+            }
+            #[allow(unused_variables)]
+            fn in_range(self, min: Self, max: Self) -> bool {
+                true $(&& self.$i.in_range(min.$i, max.$i))*
+            }
+            #[allow(unused_variables)]
+            fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+                let size = 1usize;
+                $(let size = size.checked_mul($t::range_size_checked(min.$i, max.$i)?)?;)*
+                Some(size)
+            }
+            #[allow(unused_mut, unused_variables)]
+            fn from_index_checked(index: usize, min: Self, max: Self) -> Option<Self> {
+                let mut index = index;
+                let mut ix = min.clone();
+                $(
+                    let i = match $rt::range_size_checked(min.$ri.clone(), max.$ri.clone()) {
+                        Some(size) => {
+                            let i = index % size;
+                            index /= size;
+                            i
+                        }
+                        None => core::mem::take(&mut index),
+                    };
+                    ix.$ri = $rt::from_index_checked(i, min.$ri.clone(), max.$ri.clone())?;
+                )*
+                if index == 0 {
+                    Some(ix)
+                } else {
+                    None
+                }
+            }
+            #[allow(unused_variables)]
+            fn index_u128(self, min: Self, max: Self) -> u128 {
+                let index = 0u128;
+                $(
+                    let size = $t::extent_u128(min.$i.clone(), max.$i.clone()).checked_add(1);
+                    let index = match (index, size) {
+                        (0, _) => 0,
+                        (index, Some(size)) => index.checked_mul(size).expect("index too large"),
+                        (_, None) => panic!("index too large"),
+                    }
+                    .checked_add(self.$i.index_u128(min.$i, max.$i))
+                    .expect("index too large");
+                )*
+                index
+            }
+            #[allow(unused_variables)]
+            fn manhattan_distance(self, other: Self, min: Self, max: Self) -> u128 {
+                let distance = 0u128;
+                $(
+                    let distance = distance
+                        .checked_add(self.$i.manhattan_distance(other.$i, min.$i, max.$i))
+                        .expect("distance too large");
+                )*
+                distance
+            }
+            #[allow(unused_mut, unused_variables)]
+            fn successor(self, min: Self, max: Self) -> Option<Self> {
+                let mut ix = self;
+                $(
+                    match ix.$ri.clone().successor(min.$ri.clone(), max.$ri.clone()) {
+                        Some(next) => {
+                            ix.$ri = next;
+                            return Some(ix);
+                        }
+                        None => ix.$ri = min.$ri.clone(),
+                    }
+                )*
+                None
+            }
+            #[allow(unused_mut, unused_variables)]
+            fn predecessor(self, min: Self, max: Self) -> Option<Self> {
+                let mut ix = self;
+                $(
+                    match ix.$ri.clone().predecessor(min.$ri.clone(), max.$ri.clone()) {
+                        Some(previous) => {
+                            ix.$ri = previous;
+                            return Some(ix);
+                        }
+                        None => ix.$ri = max.$ri.clone(),
+                    }
+                )*
+                None
+            }
+            #[allow(unused_mut, unused_variables)]
+            fn merge(a: (Self, Self), b: (Self, Self)) -> Option<(Self, Self)> {
+                if Ix::contains(a.clone(), Contains::Range(b.0.clone(), b.1.clone())) {
+                    return Some(a);
+                }
+                if Ix::contains(b.clone(), Contains::Range(a.0.clone(), a.1.clone())) {
+                    return Some(b);
+                }
+                // Otherwise, the boxes must coincide on every component but one.
+                let mut merged = a.clone();
+                let mut differing = 0;
+                $(
+                    if a.0.$i != b.0.$i || a.1.$i != b.1.$i {
+                        differing += 1;
+                        (merged.0.$i, merged.1.$i) =
+                            $t::merge((a.0.$i, a.1.$i), (b.0.$i, b.1.$i))?;
+                    }
+                )*
+                if differing == 1 {
+                    Some(merged)
+                } else {
+                    None
+                }
+            }
+            #[allow(unused_variables)]
+            fn overlap_size_checked(a: (Self, Self), b: (Self, Self)) -> Option<usize> {
+                let sizes = [$($t::overlap_size_checked((a.0.$i, a.1.$i), (b.0.$i, b.1.$i)),)*];
+                // An empty component makes the overlap empty, even if another one is too large.
+                if sizes.contains(&Some(0)) {
+                    return Some(0);
+                }
+                sizes.into_iter().try_fold(1usize, |size, component| size.checked_mul(component?))
+            }
+            #[allow(unused_variables)]
+            fn bounds_sorted(a: Self, b: Self) -> Bounds<Self> {
+                let sorted = ($($t::bounds_sorted(a.$i, b.$i).into_parts(),)*);
+                Bounds::new(($(sorted.$i.0,)*), ($(sorted.$i.1,)*))
+            }
+        }
+
+        impl<$($t: BoundedIx + Clone),*> BoundedIx for ($($t,)*) {
+            const MIN: Self = ($($t::MIN,)*);
+            const MAX: Self = ($($t::MAX,)*);
+            #[allow(unused_variables)]
+            fn pad_bounds(min: Self, max: Self, n: usize) -> (Self, Self) {
+                let padded = ($($t::pad_bounds(min.$i, max.$i, n),)*);
+                (($(padded.$i.0,)*), ($(padded.$i.1,)*))
+            }
+            #[allow(unused_variables)]
+            fn shrink_bounds(min: Self, max: Self, n: usize) -> Option<(Self, Self)> {
+                let shrunk = ($($t::shrink_bounds(min.$i, max.$i, n)?,)*);
+                Some((($(shrunk.$i.0,)*), ($(shrunk.$i.1,)*)))
+            }
+            #[allow(unused_variables)]
+            fn reindex_checked(self, min: Self, max: Self, to_min: Self) -> Option<Self> {
+                Some(($(self.$i.reindex_checked(min.$i, max.$i, to_min.$i)?,)*))
+            }
+        }
+
+        impl<$($t: Ix + Clone),*> CompositeIx for ($($t,)*) {
+            type AxisSizes = ($(usize_for!($t),)*);
+            #[allow(unused_variables, clippy::unused_unit)]
+            fn axis_sizes(min: Self, max: Self) -> Self::AxisSizes {
+                ($($t::range_size(min.$i, max.$i),)*)
+            }
+            #[allow(unused_assignments, unused_mut, unused_variables)]
+            fn axis_of(index: usize, axis: usize, min: Self, max: Self) -> usize {
+                if let Some(size) = Ix::range_size_checked(min.clone(), max.clone()) {
+                    assert!(index < size, "index out of range");
+                }
+                let mut index = index;
+                $(
+                    let size = $rt::range_size(min.$ri, max.$ri);
+                    if axis == $ri {
+                        return index % size;
+                    }
+                    index /= size;
+                )*
+                panic!("axis out of range")
+            }
+        }
+    };
+}
+
+impl_ix_tuple!((), ());
+impl_ix_tuple!((A 0), (A 0));
+impl_ix_tuple!((A 0, B 1), (B 1, A 0));
+impl_ix_tuple!((A 0, B 1, C 2), (C 2, B 1, A 0));
+impl_ix_tuple!((A 0, B 1, C 2, D 3), (D 3, C 2, B 1, A 0));
+impl_ix_tuple!((A 0, B 1, C 2, D 3, E 4), (E 4, D 3, C 2, B 1, A 0));
@@ -0,0 +1,181 @@
+use crate::Ix;
+use core::fmt;
+use std::panic::{catch_unwind, RefUnwindSafe};
+
+/// A violation of one of the invariants of [`Ix`], as detected by [`validate`].
+///
+/// Each variant corresponds to the invariant with the same number in the documentation of [`Ix`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PropertyViolation<T> {
+    /// Invariant 1: [`in_range`](Ix::in_range) disagrees with whether the range contains `ix`.
+    InRange { ix: T, in_range: bool },
+    /// Invariant 2: the element of the range at the position of `ix` is not `ix`.
+    Index {
+        ix: T,
+        index: usize,
+        found: Option<T>,
+    },
+    /// Invariant 3: the element at `position` in the range has a different position.
+    Enumeration {
+        position: usize,
+        index: Option<usize>,
+    },
+    /// Invariant 4: [`index_checked`](Ix::index_checked) disagrees with
+    /// [`range_size_checked`](Ix::range_size_checked) about whether positions overflow.
+    CheckedIndex { size: Option<usize> },
+    /// Invariant 5: the size of the range differs from the number of elements it yields.
+    Size { size: usize, count: usize },
+    /// Invariant 6: [`range_size_checked`](Ix::range_size_checked) disagrees with
+    /// whether counting the elements of the range overflows or panics.
+    Overflow { size: Option<usize> },
+}
+
+impl<T: fmt::Debug> fmt::Display for PropertyViolation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyViolation::InRange { ix, in_range } => write!(
+                f,
+                "invariant 1 violated: in_range returned {} for {:?}",
+                in_range, ix
+            ),
+            PropertyViolation::Index { ix, index, found } => write!(
+                f,
+                "invariant 2 violated: {:?} has index {}, but the range has {:?} there",
+                ix, index, found
+            ),
+            PropertyViolation::Enumeration { position, index } => write!(
+                f,
+                "invariant 3 violated: element {} of the range has index {:?}",
+                position, index
+            ),
+            PropertyViolation::CheckedIndex { size } => write!(
+                f,
+                "invariant 4 violated: index_checked disagrees with range size {:?}",
+                size
+            ),
+            PropertyViolation::Size { size, count } => write!(
+                f,
+                "invariant 5 violated: range size is {}, but the range has {} elements",
+                size, count
+            ),
+            PropertyViolation::Overflow { size } => write!(
+                f,
+                "invariant 6 violated: range size is {:?}, but counting the range disagrees",
+                size
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for PropertyViolation<T> {}
+
+/// A check of one invariant, given `min`, `max`, and `ix`.
+type Check<T> = fn(T, T, T) -> Result<(), PropertyViolation<T>>;
+
+/// Check the invariants of [`Ix`] for a range starting from `min` and stopping at `max` and a value `ix`,
+/// returning the first violated invariant.
+///
+/// Every invariant is checked by enumerating the range, so the range should be small.
+/// As documented for [`Ix`], an invariant is considered to hold if checking it panics.
+/// If `min` is greater than `max`, there is nothing to check.
+///
+/// # Examples
+///
+/// ```
+/// # use ix_rs::validate;
+/// assert_eq!(validate(-3i32, 3, 2), Ok(()));
+/// assert_eq!(validate(('a', 0u8), ('c', 2), ('b', 7)), Ok(()));
+/// ```
+pub fn validate<T: Ix + Copy + RefUnwindSafe>(
+    min: T,
+    max: T,
+    ix: T,
+) -> Result<(), PropertyViolation<T>> {
+    if min > max {
+        return Ok(());
+    }
+    let checks: [Check<T>; 6] = [
+        check_in_range,
+        check_index,
+        check_enumeration,
+        check_checked_index,
+        check_size,
+        check_overflow,
+    ];
+    for check in checks {
+        if let Ok(result) = catch_unwind(|| check(min, max, ix)) {
+            result?;
+        }
+    }
+    Ok(())
+}
+
+fn check_in_range<T: Ix + Copy>(min: T, max: T, ix: T) -> Result<(), PropertyViolation<T>> {
+    let in_range = ix.in_range(min, max);
+    if in_range == Ix::range(min, max).any(|x| x == ix) {
+        Ok(())
+    } else {
+        Err(PropertyViolation::InRange { ix, in_range })
+    }
+}
+
+fn check_index<T: Ix + Copy>(min: T, max: T, ix: T) -> Result<(), PropertyViolation<T>> {
+    if !ix.in_range(min, max) {
+        return Ok(());
+    }
+    let index = ix.index(min, max);
+    let found = Ix::range(min, max).nth(index);
+    if found == Some(ix) {
+        Ok(())
+    } else {
+        Err(PropertyViolation::Index { ix, index, found })
+    }
+}
+
+fn check_enumeration<T: Ix + Copy>(min: T, max: T, _: T) -> Result<(), PropertyViolation<T>> {
+    let size = match Ix::range_size_checked(min, max) {
+        Some(size) => size,
+        None => return Ok(()),
+    };
+    for (x, position) in Ix::range(min, max).zip(0..size) {
+        let index = x.index_checked(min, max);
+        if index != Some(position) {
+            return Err(PropertyViolation::Enumeration { position, index });
+        }
+    }
+    Ok(())
+}
+
+fn check_checked_index<T: Ix + Copy>(min: T, max: T, _: T) -> Result<(), PropertyViolation<T>> {
+    let size = Ix::range_size_checked(min, max);
+    let overflows = Ix::range(min, max).any(|x| x.index_checked(min, max).is_none());
+    if overflows == size.is_none() {
+        Ok(())
+    } else {
+        Err(PropertyViolation::CheckedIndex { size })
+    }
+}
+
+fn check_size<T: Ix + Copy>(min: T, max: T, _: T) -> Result<(), PropertyViolation<T>> {
+    let size = Ix::range_size(min, max);
+    let count = Ix::range(min, max).count();
+    if size == count {
+        Ok(())
+    } else {
+        Err(PropertyViolation::Size { size, count })
+    }
+}
+
+fn check_overflow<T: Ix + Copy + RefUnwindSafe>(
+    min: T,
+    max: T,
+    _: T,
+) -> Result<(), PropertyViolation<T>> {
+    let size = Ix::range_size_checked(min, max);
+    let fails = catch_unwind(|| Ix::range(min, max).count()).is_err();
+    if fails == size.is_none() {
+        Ok(())
+    } else {
+        Err(PropertyViolation::Overflow { size })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::Ix;
+
+/// An iterator over a range that produces every element along with the bounds of the range.
+///
+/// This struct is created by [`Ix::iter_with_bounds`].
+#[derive(Clone, Debug)]
+pub struct WithBounds<I: Ix> {
+    inner: I::Range,
+    min: I,
+    max: I,
+}
+
+impl<I: Ix + Clone> WithBounds<I> {
+    pub(crate) fn new(min: I, max: I) -> Self {
+        WithBounds {
+            inner: Ix::range(min.clone(), max.clone()),
+            min,
+            max,
+        }
+    }
+}
+
+impl<I: Ix + Clone> Iterator for WithBounds<I> {
+    type Item = (I, I, I);
+    fn next(&mut self) -> Option<Self::Item> {
+        let ix = self.inner.next()?;
+        Some((ix, self.min.clone(), self.max.clone()))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
@@ -0,0 +1,57 @@
+use crate::Ix;
+
+/// An iterator over the elements of a range between two of its elements, in the order of the range.
+///
+/// This struct is created by [`Ix::range_within`].
+#[derive(Clone, Debug)]
+pub struct RangeWithin<I> {
+    next: Option<I>,
+    last: I,
+    min: I,
+    max: I,
+}
+
+impl<I: Ix + Clone> RangeWithin<I> {
+    pub(crate) fn new(start: I, end: I, min: I, max: I) -> Self {
+        assert_ordered!(min, max);
+        let start = clip(start, &min, &max);
+        let last = clip(end, &min, &max);
+        let first = start.clone().index(min.clone(), max.clone());
+        let next = if first <= last.clone().index(min.clone(), max.clone()) {
+            Some(start)
+        } else {
+            None
+        };
+        RangeWithin {
+            next,
+            last,
+            min,
+            max,
+        }
+    }
+}
+
+fn clip<I: Ix + Clone>(ix: I, min: &I, max: &I) -> I {
+    if ix < *min {
+        min.clone()
+    } else if ix > *max {
+        max.clone()
+    } else {
+        ix
+    }
+}
+
+impl<I: Ix + Clone> Iterator for RangeWithin<I> {
+    type Item = I;
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        if current != self.last {
+            self.next = current
+                .clone()
+                .successor(self.min.clone(), self.max.clone());
+        }
+        Some(current)
+    }
+}
+
+impl<I: Ix + Clone> core::iter::FusedIterator for RangeWithin<I> {}
@@ -0,0 +1,105 @@
+use ix_rs::{BoundedIx, CompositeIx, Ix};
+
+#[test]
+fn empty_array_is_a_point() {
+    let empty: [u8; 0] = [];
+    assert!(Ix::range(empty, empty).eq([empty]));
+    assert_eq!(empty.index(empty, empty), 0);
+    assert_eq!(Ix::range_size(empty, empty), 1);
+    assert_eq!(Ix::from_index_checked(0, empty, empty), Some(empty));
+    assert_eq!(Ix::from_index_checked(1, empty, empty), None);
+    assert_eq!(empty.successor(empty, empty), None);
+    assert_eq!(empty.predecessor(empty, empty), None);
+}
+
+#[test]
+fn empty_array_in_tuple() {
+    let (min, max): (([u8; 0], u8), _) = (([], 3), ([], 9));
+    assert_eq!(Ix::range_size(min, max), Ix::range_size(3u8, 9));
+    assert!(Ix::range(min, max).map(|(_, x)| x).eq(3..=9));
+    assert_eq!(([], 5u8).index(min, max), 5u8.index(3, 9));
+}
+
+#[test]
+fn array_row_major() {
+    assert!(Ix::range([0u8, 0], [1, 2]).eq([[0, 0], [0, 1], [0, 2], [1, 0], [1, 1], [1, 2]]));
+    assert_eq!([1i32, 2, 3].index([0, 0, 0], [2, 3, 4]), 33);
+    assert_eq!(Ix::from_index(33, [0i32, 0, 0], [2, 3, 4]), [1, 2, 3]);
+    assert_eq!([0u8, 2].successor([0, 0], [1, 2]), Some([1, 0]));
+    assert_eq!([1u8, 0].predecessor([0, 0], [1, 2]), Some([0, 2]));
+    assert_eq!([1u8, 0].index_u128([0, 0], [1, 2]), 3);
+    assert_eq!([0u8, 0].manhattan_distance([1, 2], [0, 0], [1, 2]), 3);
+}
+
+#[test]
+fn bounded_array() {
+    assert_eq!(<[u8; 2] as BoundedIx>::MIN, [0, 0]);
+    assert_eq!(<[i8; 3] as BoundedIx>::MAX, [127; 3]);
+    assert_eq!(
+        BoundedIx::pad_bounds([1u8, 5], [3, 254], 2),
+        ([0, 3], [5, 255])
+    );
+    assert_eq!(
+        BoundedIx::shrink_bounds([1u8, 5], [3, 9], 1),
+        Some(([2, 6], [2, 8]))
+    );
+    assert_eq!(BoundedIx::shrink_bounds([1u8, 5], [3, 9], 2), None);
+}
+
+#[test]
+#[should_panic(expected = "min is greater than max")]
+fn array_unordered_component() {
+    Ix::range([0u8, 5], [1, 2]);
+}
+
+#[test]
+fn axis_sizes_per_component() {
+    assert_eq!(
+        CompositeIx::axis_sizes([0u8, 10, 5], [3, 19, 5]),
+        [4, 10, 1]
+    );
+    assert_eq!(<[u8; 0] as CompositeIx>::axis_sizes([], []), []);
+    assert_eq!(
+        CompositeIx::axis_sizes((0u8, -1i32, 'a'), (1, 1, 'e')),
+        (2, 3, 5)
+    );
+    assert_eq!(CompositeIx::axis_sizes((), ()), ());
+}
+
+#[test]
+#[should_panic(expected = "range size too large")]
+fn axis_sizes_overflow() {
+    CompositeIx::axis_sizes([0u128, 0], [1, u128::MAX]);
+}
+
+#[test]
+fn axis_of_matches_full_decode() {
+    let (min, max) = ((2u8, 0u8), (11, 13));
+    for linear in 0..Ix::range_size(min, max) {
+        let (row, col) = Ix::from_index(linear, min, max);
+        assert_eq!(CompositeIx::axis_of(linear, 0, min, max), row.index(2, 11));
+        assert_eq!(CompositeIx::axis_of(linear, 1, min, max), col.index(0, 13));
+    }
+    let (min, max) = ([0u8, 5, 1], [3, 7, 4]);
+    for linear in 0..Ix::range_size(min, max) {
+        let ix = Ix::from_index(linear, min, max);
+        for axis in 0..3 {
+            assert_eq!(
+                CompositeIx::axis_of(linear, axis, min, max),
+                ix[axis].index(min[axis], max[axis])
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "axis out of range")]
+fn axis_of_bad_axis() {
+    CompositeIx::axis_of(0, 2, (0u8, 0u8), (3, 3));
+}
+
+#[test]
+#[should_panic(expected = "index out of range")]
+fn axis_of_bad_index() {
+    CompositeIx::axis_of(16, 0, [0u8; 2], [3; 2]);
+}
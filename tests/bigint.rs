@@ -0,0 +1,81 @@
+#![cfg(feature = "num-bigint")]
+
+use ix_rs::Ix;
+use num_bigint::{BigInt, BigUint};
+
+fn big(n: i64) -> BigInt {
+    BigInt::from(n) * BigInt::from(u128::MAX)
+}
+
+#[test]
+fn bigint_small_window() {
+    let min = big(-3) - 2u8;
+    let max = big(-3) + 2u8;
+    let range: Vec<BigInt> = Ix::range(min.clone(), max.clone()).collect();
+    assert_eq!(range.len(), 5);
+    assert_eq!(Ix::range_size(min.clone(), max.clone()), 5);
+    for (i, ix) in range.iter().enumerate() {
+        assert!(ix.clone().in_range(min.clone(), max.clone()));
+        assert_eq!(ix.clone().index(min.clone(), max.clone()), i);
+    }
+    assert!(!(max.clone() + 1u8).in_range(min.clone(), max.clone()));
+    assert!(!(min.clone() - 1u8).in_range(min, max));
+}
+
+#[test]
+fn bigint_huge_window() {
+    let min = big(-5);
+    let max = big(5);
+    assert_eq!(Ix::range_size_checked(min.clone(), max.clone()), None);
+    assert_eq!(big(1).index_checked(min.clone(), max.clone()), None);
+    assert_eq!(
+        (min.clone() + 7u8).index_checked(min.clone(), max.clone()),
+        Some(7)
+    );
+    assert!(big(2).in_range(min.clone(), max.clone()));
+    assert_eq!(Ix::range(min.clone(), max).nth(3), Some(min + 3u8));
+}
+
+#[test]
+fn biguint_small_window() {
+    let min = BigUint::from(u128::MAX) - 1u8;
+    let max = BigUint::from(u128::MAX) + 1u8;
+    assert!(Ix::range(min.clone(), max.clone()).eq([min.clone(), min.clone() + 1u8, max.clone()]));
+    assert_eq!(Ix::range_size(min.clone(), max.clone()), 3);
+    assert_eq!(max.clone().index(min.clone(), max.clone()), 2);
+    assert!(Ix::range(min.clone(), max.clone())
+        .map(|x| x.index(min.clone(), max.clone()))
+        .eq(0..3));
+}
+
+#[test]
+fn bigint_successor_predecessor() {
+    let min = big(7);
+    let max = big(7) + 3u8;
+    let range: Vec<BigInt> = Ix::range(min.clone(), max.clone()).collect();
+    for (i, ix) in range.iter().enumerate() {
+        assert_eq!(
+            ix.clone().successor(min.clone(), max.clone()).as_ref(),
+            range.get(i + 1)
+        );
+        assert_eq!(Ix::from_index(i, min.clone(), max.clone()), *ix);
+    }
+    assert_eq!(min.clone().predecessor(min.clone(), max.clone()), None);
+    assert_eq!(
+        max.clone().predecessor(min.clone(), max.clone()),
+        Some(max - 1u8)
+    );
+}
+
+#[test]
+fn biguint_single_element() {
+    let x = BigUint::from(42u8);
+    assert!(Ix::range(x.clone(), x.clone()).eq([x.clone()]));
+    assert_eq!(Ix::range_size(x.clone(), x.clone()), 1);
+}
+
+#[test]
+#[should_panic(expected = "min is greater than max")]
+fn bigint_unordered() {
+    Ix::range(BigInt::from(1), BigInt::from(0));
+}
@@ -0,0 +1,240 @@
+use ix_rs::{BoundedIx, Bounds, Ix, RangeError};
+use std::cmp::Reverse;
+
+fn full_range_size<T: BoundedIx>() -> Option<usize> {
+    Ix::range_size_checked(T::MIN, T::MAX)
+}
+
+fn full_range_first_last<T: BoundedIx + Copy>() -> bool {
+    let mut range = T::full_range();
+    range.next() == Some(T::MIN) && range.last() == Some(T::MAX)
+}
+
+#[test]
+fn full_range_u8() {
+    assert_eq!(u8::full_range().count(), 256);
+    assert!(u8::full_range().eq(0..=255));
+}
+
+#[test]
+fn full_range_i8() {
+    assert_eq!(i8::full_range().count(), 256);
+    assert!(i8::full_range().eq(-128..=127));
+}
+
+#[test]
+fn full_range_generic() {
+    assert_eq!(full_range_size::<u8>(), Some(256));
+    assert_eq!(full_range_size::<u16>(), Some(65536));
+    assert_eq!(full_range_size::<u128>(), None);
+    assert!(full_range_first_last::<u8>());
+    assert!(full_range_first_last::<i16>());
+}
+
+#[test]
+fn full_range_is_lazy() {
+    assert_eq!(u128::full_range().next(), Some(0));
+    assert_eq!(i128::full_range().nth(3), Some(i128::MIN + 3));
+}
+
+#[test]
+fn pad_bounds_saturates() {
+    assert_eq!(BoundedIx::pad_bounds(2i32, 8, 5), (-3, 13));
+    assert_eq!(BoundedIx::pad_bounds(2i32, 8, 0), (2, 8));
+    assert_eq!(BoundedIx::pad_bounds(i32::MIN + 2, 0, 5), (i32::MIN, 5));
+    assert_eq!(BoundedIx::pad_bounds(0, i32::MAX - 1, 5), (-5, i32::MAX));
+    assert_eq!(BoundedIx::pad_bounds(100i8, 120, 200), (-100, i8::MAX));
+    assert_eq!(BoundedIx::pad_bounds(3u8, 5, usize::MAX), (0, u8::MAX));
+    assert_eq!(
+        BoundedIx::pad_bounds(u128::MAX - 1, u128::MAX - 1, 1),
+        (u128::MAX - 2, u128::MAX)
+    );
+    assert_eq!(
+        BoundedIx::pad_bounds(i128::MIN + 1, i128::MAX - 1, 2),
+        (i128::MIN, i128::MAX)
+    );
+}
+
+#[test]
+fn shrink_bounds_detects_empty() {
+    assert_eq!(BoundedIx::shrink_bounds(0i32, 10, 0), Some((0, 10)));
+    assert_eq!(BoundedIx::shrink_bounds(0i32, 10, 5), Some((5, 5)));
+    assert_eq!(BoundedIx::shrink_bounds(0i32, 10, 6), None);
+    assert_eq!(BoundedIx::shrink_bounds(0i32, 9, 4), Some((4, 5)));
+    assert_eq!(BoundedIx::shrink_bounds(0i32, 9, 5), None);
+    assert_eq!(BoundedIx::shrink_bounds(7u8, 7, 0), Some((7, 7)));
+    assert_eq!(BoundedIx::shrink_bounds(7u8, 7, 1), None);
+    assert_eq!(
+        BoundedIx::shrink_bounds(i128::MIN, i128::MAX, usize::MAX),
+        Some((
+            i128::MIN + usize::MAX as i128,
+            i128::MAX - usize::MAX as i128
+        ))
+    );
+    assert_eq!(
+        BoundedIx::shrink_bounds(i8::MIN, i8::MAX, 127),
+        Some((-1, 0))
+    );
+    assert_eq!(BoundedIx::shrink_bounds(i8::MIN, i8::MAX, 128), None);
+}
+
+#[test]
+fn full_range_signed_size() {
+    assert_eq!(full_range_size::<i8>(), Some(256));
+    assert_eq!(full_range_size::<i16>(), Some(65536));
+    assert_eq!(i8::MAX.index(i8::MIN, i8::MAX), 255);
+    assert_eq!(Ix::range_size_checked(i64::MIN, i64::MAX), None);
+    assert_eq!(
+        0i64.index_checked(i64::MIN, i64::MAX),
+        usize::try_from(1u64 << 63).ok()
+    );
+}
+
+#[test]
+fn extent_u128_signed() {
+    assert_eq!(Ix::extent_u128(i8::MIN, i8::MAX), 255);
+    assert_eq!(Ix::extent_u128(i128::MIN, i128::MAX), u128::MAX);
+}
+
+#[test]
+fn bounded_tuples() {
+    assert_eq!(<(u8, i8)>::MIN, (0, -128));
+    assert_eq!(<(u8, i8)>::full_range().count(), 65536);
+    assert_eq!(
+        BoundedIx::pad_bounds((2i32, 250u8), (8, 252), 5),
+        ((-3, 245), (13, 255))
+    );
+    assert_eq!(
+        BoundedIx::shrink_bounds((0i32, 0u8), (10, 20), 5),
+        Some(((5, 5), (5, 15)))
+    );
+    assert_eq!(BoundedIx::shrink_bounds((0i32, 0u8), (10, 20), 6), None);
+}
+
+#[test]
+fn reindex_numeric() {
+    for (x, y) in (3i32..=7).zip(100..) {
+        assert_eq!(x.reindex(3, 7, 100), y);
+    }
+    assert_eq!(5i32.reindex(3, 7, 100), 102);
+    assert_eq!((-1i8).reindex(i8::MIN, i8::MAX, 0), i8::MAX);
+    assert_eq!(0i8.reindex_checked(i8::MIN, i8::MAX, 0), None);
+    assert_eq!(u128::MAX.reindex_checked(0, u128::MAX, 0), Some(u128::MAX));
+    assert_eq!(u128::MAX.reindex_checked(0, u128::MAX, 1), None);
+}
+
+#[test]
+fn reindex_composite() {
+    assert_eq!(
+        (4i32, 'b').reindex((3, 'a'), (7, 'z'), (100, 'A')),
+        (101, 'B')
+    );
+    assert_eq!([2u8, 3].reindex([0, 0], [4, 4], [10, 20]), [12, 23]);
+    assert_eq!((1u8, 5u8).reindex_checked((0, 4), (2, 6), (10, 255)), None);
+    assert_eq!(
+        '\u{D7FF}'.reindex('\u{D7FE}', '\u{D7FF}', '\u{D7FF}'),
+        '\u{E000}'
+    );
+}
+
+#[test]
+#[should_panic(expected = "reindexed value is out of bounds")]
+fn reindex_out_of_bounds() {
+    200u8.reindex(0, 255, 100);
+}
+
+#[test]
+fn bounds_zero_to() {
+    let bounds = Bounds::<i64>::zero_to(5);
+    assert_eq!((*bounds.min(), *bounds.max()), (0, 4));
+    assert!(Ix::range_in(&bounds).eq(0..5));
+    assert_eq!(Bounds::<u8>::zero_to(256), Bounds::new(0, 255));
+    assert_eq!(Bounds::<u128>::zero_to(1), Bounds::new(0, 0));
+}
+
+#[test]
+#[should_panic(expected = "range is empty")]
+fn bounds_zero_to_empty() {
+    Bounds::<u8>::zero_to(0);
+}
+
+#[test]
+#[should_panic(expected = "size is not representable by the type")]
+fn bounds_zero_to_too_large() {
+    Bounds::<u8>::zero_to(257);
+}
+
+#[test]
+fn bounds_conversions() {
+    let bounds: Bounds<i32> = (0, 9).try_into().unwrap();
+    assert_eq!(bounds, Bounds::new(0, 9));
+    assert_eq!(bounds.into_parts(), (0, 9));
+    assert_eq!(
+        Bounds::try_from((5u8, 5)).map(Bounds::into_parts),
+        Ok((5, 5))
+    );
+    assert_eq!(Bounds::try_from((9i32, 0)), Err(RangeError));
+    assert_eq!(
+        Bounds::try_from(((0u8, 5u8), (1, 2))).map(Bounds::into_parts),
+        Ok(((0, 5), (1, 2)))
+    );
+    assert_eq!(RangeError.to_string(), "min is greater than max");
+}
+
+#[test]
+fn bounds_sorted_scalar_and_composite() {
+    assert_eq!(Ix::bounds_sorted(9i32, -3), Bounds::new(-3, 9));
+    assert_eq!(Ix::bounds_sorted(-3i32, 9), Bounds::new(-3, 9));
+    assert_eq!(Ix::bounds_sorted('q', 'q'), Bounds::new('q', 'q'));
+    assert_eq!(
+        Ix::bounds_sorted((0u8, 5u8), (3, 1)),
+        Bounds::new((0, 1), (3, 5))
+    );
+    assert_eq!(
+        Ix::bounds_sorted((4u8, 5u8), (3, 1)),
+        Bounds::new((3, 1), (4, 5))
+    );
+    assert_eq!(
+        Ix::bounds_sorted([7u8, 0, 4], [1, 9, 4]),
+        Bounds::new([1, 0, 4], [7, 9, 4])
+    );
+    assert_eq!(
+        Ix::bounds_sorted(Reverse(2u8), Reverse(5)),
+        Bounds::new(Reverse(5), Reverse(2))
+    );
+    assert_eq!(
+        Ix::bounds_sorted(Reverse((0u8, 5u8)), Reverse((3, 1))),
+        Bounds::new(Reverse((3, 5)), Reverse((0, 1)))
+    );
+}
+
+fn bisect_to_singletons(bounds: Bounds<i32>, out: &mut Vec<i32>) {
+    let (first, second) = bounds.bisect();
+    let size = |b: &Bounds<i32>| Ix::range_size(*b.min(), *b.max());
+    let total = size(&first) + second.as_ref().map_or(0, size);
+    assert_eq!(total, size(&bounds));
+    match second {
+        None => out.push(*first.min()),
+        Some(second) => {
+            assert!(size(&first) - size(&second) <= 1);
+            bisect_to_singletons(first, out);
+            bisect_to_singletons(second, out);
+        }
+    }
+}
+
+#[test]
+fn bisect_recursively() {
+    for max in 0..40 {
+        let mut out = Vec::new();
+        bisect_to_singletons(Bounds::new(-5, max), &mut out);
+        assert!(out.into_iter().eq(-5..=max));
+    }
+    assert_eq!(
+        Bounds::new(i64::MIN, i64::MIN + 2).bisect(),
+        (
+            Bounds::new(i64::MIN, i64::MIN + 1),
+            Some(Bounds::new(i64::MIN + 2, i64::MIN + 2))
+        )
+    );
+}
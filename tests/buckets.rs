@@ -0,0 +1,205 @@
+use ix_rs::Ix;
+
+#[test]
+fn quantize_boundaries() {
+    for x in 0i32..=99 {
+        assert_eq!(x.quantize(0, 99, 10), x as usize / 10);
+    }
+    assert_eq!(5u8.quantize(5, 5, 3), 0);
+    assert_eq!(
+        (u64::MAX - 1).quantize(0, u64::MAX - 1, usize::MAX),
+        usize::MAX - 1
+    );
+}
+
+#[test]
+fn quantize_uneven() {
+    let buckets: Vec<_> = Ix::range(0i32, 9).map(|x| x.quantize(0, 9, 3)).collect();
+    assert_eq!(buckets, [0, 0, 0, 0, 1, 1, 1, 2, 2, 2]);
+    let buckets: Vec<_> = Ix::range(0i32, 1).map(|x| x.quantize(0, 1, 4)).collect();
+    assert_eq!(buckets, [0, 2]);
+}
+
+#[test]
+#[should_panic(expected = "number of buckets is zero")]
+fn quantize_zero_buckets() {
+    3i32.quantize(0, 9, 0);
+}
+
+#[test]
+fn bucket_start_round_trip() {
+    for buckets in 1..=12 {
+        for b in 0..buckets {
+            if let Some(x) = Ix::bucket_start_checked(b, 0i32, 9, buckets) {
+                assert_eq!(x.quantize(0, 9, buckets), b);
+                assert!(x == 0 || (x - 1).quantize(0, 9, buckets) < b);
+            }
+        }
+        assert_eq!(Ix::bucket_start_checked(buckets, 0i32, 9, buckets), None);
+    }
+}
+
+#[test]
+fn bucket_midpoint_placement() {
+    assert_eq!(Ix::bucket_midpoint(0, 0i32, 99, 10), 4);
+    assert_eq!(Ix::bucket_midpoint(9, 0i32, 99, 10), 94);
+    assert_eq!(Ix::bucket_midpoint(0, 0i32, 9, 3), 1);
+    assert_eq!(Ix::bucket_midpoint(1, 0i32, 9, 3), 5);
+    assert_eq!(Ix::bucket_midpoint(2, 0i32, 9, 3), 8);
+    for buckets in 1..=12 {
+        for b in 0..buckets {
+            if let Some(x) = Ix::bucket_midpoint_checked(b, 0i32, 9, buckets) {
+                assert_eq!(x.quantize(0, 9, buckets), b);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "bucket out of range or empty")]
+fn bucket_start_out_of_range() {
+    Ix::bucket_start(10, 0i32, 99, 10);
+}
+
+#[test]
+fn histogram_bins_tile() {
+    for size in 1i32..=40 {
+        for buckets in 1..=size as usize {
+            let bins: Vec<_> = Ix::histogram_bins(-7, size - 8, buckets).collect();
+            assert_eq!(bins.len(), buckets);
+            assert_eq!(bins[0].0, -7);
+            assert_eq!(bins[buckets - 1].1, size - 8);
+            for pair in bins.windows(2) {
+                assert_eq!(pair[0].1 + 1, pair[1].0);
+            }
+            let sizes: Vec<_> = bins.iter().map(|&(lo, hi)| hi - lo + 1).collect();
+            let smallest = *sizes.iter().min().unwrap();
+            let largest = *sizes.iter().max().unwrap();
+            assert!(largest - smallest <= 1);
+            for (bucket, &(lo, hi)) in bins.iter().enumerate() {
+                assert_eq!(lo.quantize(-7, size - 8, buckets), bucket);
+                assert_eq!(hi.quantize(-7, size - 8, buckets), bucket);
+            }
+        }
+    }
+}
+
+#[test]
+fn histogram_bins_chars() {
+    assert!(Ix::histogram_bins('a', 'f', 2).eq([('a', 'c'), ('d', 'f')]));
+}
+
+#[test]
+#[should_panic(expected = "number of buckets is zero")]
+fn histogram_bins_zero_buckets() {
+    Ix::histogram_bins(0i32, 9, 0);
+}
+
+#[test]
+fn tiled_lookup_cycles() {
+    let tiled: Vec<_> = Ix::range(0i32, 9)
+        .map(|x| x.tiled_lookup(0, 9, 0, 2))
+        .collect();
+    assert_eq!(tiled, [0, 1, 2, 0, 1, 2, 0, 1, 2, 0]);
+    assert_eq!((-3i8).tiled_lookup(-5, 5, 10, 12), 12);
+    assert_eq!(9u8.tiled_lookup(0, 9, 4, 4), 4);
+}
+
+#[test]
+fn tiled_lookup_extremes() {
+    assert_eq!(
+        u128::MAX.tiled_lookup(0, u128::MAX, 0, u128::MAX),
+        u128::MAX
+    );
+    assert_eq!(u128::MAX.tiled_lookup(0, u128::MAX, 1, 2), 2);
+    assert_eq!(
+        i128::MAX.tiled_lookup(i128::MIN, i128::MAX, i128::MIN, -1),
+        -1
+    );
+}
+
+#[test]
+fn tiled_lookup_composite() {
+    let (min, max) = ((0u8, 0u8), (3, 3));
+    assert_eq!((1, 2).tiled_lookup(min, max, (5, 5), (6, 6)), (6, 5));
+    assert_eq!('e'.tiled_lookup('a', 'z', 'x', 'y'), 'x');
+}
+
+#[test]
+fn mirrored_lookup_pattern() {
+    let mirrored: Vec<_> = Ix::range(0i32, 11)
+        .map(|x| x.mirrored_lookup(0, 11, 0, 2))
+        .collect();
+    assert_eq!(mirrored, [0, 1, 2, 2, 1, 0, 0, 1, 2, 2, 1, 0]);
+    assert_eq!(2i32.mirrored_lookup(0, 11, 0, 2), 2);
+    assert_eq!(3i32.mirrored_lookup(0, 11, 0, 2), 2);
+    assert_eq!(5i32.mirrored_lookup(0, 11, 0, 2), 0);
+    assert_eq!(6i32.mirrored_lookup(0, 11, 0, 2), 0);
+    assert_eq!(7u8.mirrored_lookup(0, 9, 4, 4), 4);
+}
+
+#[test]
+fn mirrored_lookup_extremes() {
+    assert_eq!(
+        u128::MAX.mirrored_lookup(0, u128::MAX, 0, u128::MAX),
+        u128::MAX
+    );
+    assert_eq!(u128::MAX.mirrored_lookup(0, u128::MAX, 0, u128::MAX / 2), 0);
+    assert_eq!(i128::MAX.mirrored_lookup(i128::MIN, i128::MAX, 0, 1), 0);
+    assert_eq!(
+        (2u8, 'c').mirrored_lookup((0, 'a'), (5, 'z'), (0, 'a'), (1, 'b')),
+        (0, 'b')
+    );
+}
+
+#[test]
+fn mirrored_lookup_period_overflow() {
+    let half = 1u128 << 127;
+    assert_eq!((half | 5).mirrored_lookup(0, u128::MAX, 0, half), half - 4);
+    assert_eq!((half + 1).mirrored_lookup(0, u128::MAX, 0, half), half);
+    let half = 1usize << (usize::BITS - 1);
+    assert_eq!(
+        ((half | 5),).mirrored_lookup((0,), (usize::MAX - 1,), (0,), (half,)),
+        (half - 4,)
+    );
+    assert_eq!(
+        ((half + 1),).mirrored_lookup((0,), (usize::MAX - 1,), (0,), (half,)),
+        (half,)
+    );
+}
+
+#[test]
+fn remap_slider_to_palette() {
+    assert_eq!(50i32.remap(0, 100, 0u8, 255), 128);
+    assert_eq!(0i32.remap(0, 100, 0u8, 255), 0);
+    assert_eq!(100i32.remap(0, 100, 0u8, 255), 255);
+    for x in 0i32..=100 {
+        let expected = (f64::from(x) * 2.55).round();
+        assert!((f64::from(x.remap(0, 100, 0u8, 255)) - expected).abs() <= 1.0);
+    }
+    assert_eq!(255u8.remap(0, 255, 0i32, 100), 100);
+    assert_eq!(128u8.remap(0, 255, 0i32, 100), 50);
+}
+
+#[test]
+fn remap_single_element() {
+    assert_eq!(7i32.remap(7, 7, 10u8, 20), 10);
+    assert_eq!(7i32.remap(0, 10, 'q', 'q'), 'q');
+    assert_eq!(u16::MAX.remap(0, u16::MAX, -1i8, 1), 1);
+}
+
+#[test]
+fn remap_composite() {
+    assert_eq!((1u8, 1u8).remap((0, 0), (2, 2), 0u8, 16), 8);
+    assert_eq!(16u8.remap(0, 16, (0u8, 0u8), (2, 2)), (2, 2));
+}
+
+#[test]
+fn remap_usize_boundary() {
+    let max = usize::MAX - 1;
+    assert_eq!(max.remap(0, max, 0usize, max), max);
+    assert_eq!((max / 2).remap(0, max, 0usize, max), max / 2);
+    assert_eq!(max.remap(0, max, 0u8, 255), 255);
+    assert_eq!(255u8.remap(0, 255, 0usize, max), max);
+    assert_eq!(1u8.remap(0, 2, 0usize, max), max / 2);
+}
@@ -0,0 +1,64 @@
+use ix_rs::{BoundedIx, CharIx, Ix};
+
+#[test]
+fn successor_into_gap() {
+    assert_eq!('\u{D7FE}'.successor('\0', char::MAX), Some('\u{D7FF}'));
+    assert_eq!('\u{D7FF}'.successor('\0', char::MAX), Some('\u{E000}'));
+}
+
+#[test]
+fn predecessor_out_of_gap() {
+    assert_eq!('\u{E000}'.predecessor('\0', char::MAX), Some('\u{D7FF}'));
+    assert_eq!('\u{E001}'.predecessor('\0', char::MAX), Some('\u{E000}'));
+}
+
+#[test]
+fn successor_at_max() {
+    assert_eq!(char::MAX.successor('\0', char::MAX), None);
+    assert_eq!('\u{10FFFE}'.successor('\0', char::MAX), Some(char::MAX));
+}
+
+#[test]
+fn predecessor_at_min() {
+    assert_eq!('\0'.predecessor('\0', char::MAX), None);
+}
+
+#[test]
+fn index_across_gap() {
+    assert_eq!('\u{E000}'.index('\u{D7FF}', '\u{E001}'), 1);
+    assert_eq!(Ix::range_size('\u{D7FF}', '\u{E001}'), 3);
+    assert_eq!(Ix::from_index(1, '\u{D7FF}', '\u{E001}'), '\u{E000}');
+    assert_eq!(Ix::from_index_checked(3, '\u{D7FF}', '\u{E001}'), None);
+    assert_eq!(Ix::range_size('\0', char::MAX), 0x110000 - 0x800);
+}
+
+#[test]
+fn bounded_char() {
+    assert_eq!(char::full_range().count(), 0x110000 - 0x800);
+    assert_eq!(
+        BoundedIx::pad_bounds('\u{D7FF}', '\u{E000}', 1),
+        ('\u{D7FE}', '\u{E001}')
+    );
+    assert_eq!(
+        BoundedIx::pad_bounds('a', 'z', usize::MAX),
+        ('\0', char::MAX)
+    );
+    assert_eq!(
+        BoundedIx::shrink_bounds('\u{D7FF}', '\u{E000}', 0),
+        Some(('\u{D7FF}', '\u{E000}'))
+    );
+    assert_eq!(BoundedIx::shrink_bounds('\u{D7FF}', '\u{E000}', 1), None);
+}
+
+#[test]
+fn scalar_and_codepoint_indices() {
+    let (min, max) = ('\u{D000}', '\u{F000}');
+    for c in ['\u{E000}', '\u{E123}', max] {
+        assert_eq!(c.index_scalar(min, max) as usize, c.index(min, max));
+        assert_eq!(c.index_codepoint(min, max) - c.index_scalar(min, max), 2048);
+    }
+    for c in [min, '\u{D7FF}'] {
+        assert_eq!(c.index_codepoint(min, max), c.index_scalar(min, max));
+    }
+    assert_eq!('z'.index_codepoint('a', 'z'), 25);
+}
@@ -0,0 +1,60 @@
+use ix_rs::Ix;
+
+#[test]
+fn overlapping_chunks_stride() {
+    let chunks: Vec<_> = Ix::overlapping_chunks(0i32, 9, 4, 1).collect();
+    assert_eq!(chunks, [(0, 3), (3, 6), (6, 9)]);
+    for pair in chunks.windows(2) {
+        assert_eq!(pair[1].0 - pair[0].0, 3);
+        assert_eq!(pair[0].1, pair[1].0);
+    }
+}
+
+#[test]
+fn overlapping_chunks_final_chunk() {
+    assert!(Ix::overlapping_chunks(0i32, 10, 4, 1).eq([(0, 3), (3, 6), (6, 9), (9, 10)]));
+    assert!(Ix::overlapping_chunks(0i32, 2, 4, 1).eq([(0, 2)]));
+    assert!(Ix::overlapping_chunks(5i32, 5, 1, 0).eq([(5, 5)]));
+    assert!(Ix::overlapping_chunks(0u8, 255, 200, 100).eq([(0, 199), (100, 255)]));
+}
+
+#[test]
+fn overlapping_chunks_windows() {
+    assert!(Ix::overlapping_chunks('a', 'd', 2, 1).eq([('a', 'b'), ('b', 'c'), ('c', 'd')]));
+}
+
+#[test]
+#[should_panic(expected = "overlap is not less than chunk size")]
+fn overlapping_chunks_overlap_too_large() {
+    Ix::overlapping_chunks(0i32, 9, 4, 4);
+}
+
+#[test]
+fn chunks_exact_remainder() {
+    let (chunks, remainder) = Ix::chunks_exact(0i32, 9, 4);
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks.eq([(0, 3), (4, 7)]));
+    assert_eq!(remainder, Some((8, 9)));
+    let (chunks, remainder) = Ix::chunks_exact(0i32, 2, 4);
+    assert_eq!(chunks.count(), 0);
+    assert_eq!(remainder, Some((0, 2)));
+}
+
+#[test]
+fn chunks_exact_evenly_divisible() {
+    let (chunks, remainder) = Ix::chunks_exact(-6i32, 5, 3);
+    assert!(chunks.eq([(-6, -4), (-3, -1), (0, 2), (3, 5)]));
+    assert_eq!(remainder, None);
+    let (chunks, remainder) = Ix::chunks_exact(0u8, 255, 128);
+    assert!(chunks.eq([(0, 127), (128, 255)]));
+    assert_eq!(remainder, None);
+    let (chunks, remainder) = Ix::chunks_exact((0u8, 0u8), (1, 2), 2);
+    assert!(chunks.eq([((0, 0), (0, 1)), ((0, 2), (1, 0)), ((1, 1), (1, 2))]));
+    assert_eq!(remainder, None);
+}
+
+#[test]
+#[should_panic(expected = "chunk size is zero")]
+fn chunks_exact_zero_size() {
+    Ix::chunks_exact(0i32, 9, 0);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "alloc")]
+
+use ix_rs::{Ix, OutOfRange};
+
+#[test]
+fn try_collect_vec_len() {
+    let vec = Ix::try_collect_vec(-5i64, 10).unwrap();
+    assert_eq!(vec.len(), Ix::range_size(-5i64, 10));
+    assert!(vec.capacity() >= vec.len());
+    assert_eq!(vec.first(), Some(&-5));
+    assert_eq!(vec.last(), Some(&10));
+    let vec = Ix::try_collect_vec((0u8, 'a'), (2, 'c')).unwrap();
+    assert_eq!(vec.len(), 9);
+    assert_eq!(vec[4], (1, 'b'));
+}
+
+#[test]
+fn try_collect_vec_oversized() {
+    assert_eq!(Ix::try_collect_vec(0u128, u128::MAX), None);
+    assert_eq!(Ix::try_collect_vec(i128::MIN, i128::MAX), None);
+}
+
+#[test]
+fn map_capacity_hint() {
+    let capacity = Ix::map_capacity(-50i32, 49).unwrap();
+    assert_eq!(capacity, 100);
+    assert!(Vec::<i32>::with_capacity(capacity).capacity() >= 100);
+    assert_eq!(
+        Ix::map_capacity(0usize, isize::MAX as usize - 1),
+        Some(isize::MAX as usize)
+    );
+    assert_eq!(Ix::map_capacity(0usize, isize::MAX as usize), None);
+    assert_eq!(Ix::map_capacity(0u128, u128::MAX), None);
+}
+
+#[test]
+fn to_btreemap_ordered() {
+    let mut map = Ix::to_btreemap(0i32, 4, |x| x * x);
+    assert_eq!(map.len(), 5);
+    assert!(map.keys().copied().eq(0..=4));
+    assert!(map.values().copied().eq([0, 1, 4, 9, 16]));
+    assert_eq!(map.remove(&2), Some(4));
+    assert!(map.into_iter().eq([(0, 0), (1, 1), (3, 9), (4, 16)]));
+    let map = Ix::to_btreemap((0u8, 0u8), (1, 1), |(a, b)| a + b);
+    assert!(map.into_values().eq([0, 1, 1, 2]));
+}
+
+#[test]
+fn sorted_set_normalizes() {
+    assert_eq!(
+        Ix::sorted_set(&[9i32, -3, 4, 9, 0, 4, 4, -3], -5, 10),
+        Ok(vec![-3, 0, 4, 9])
+    );
+    assert_eq!(<i32 as Ix>::sorted_set(&[], -5, 10), Ok(vec![]));
+    assert_eq!(
+        Ix::sorted_set(&[(1u8, 2u8), (0, 3), (1, 2), (1, 0)], (0, 0), (3, 3)),
+        Ok(vec![(0, 3), (1, 0), (1, 2)])
+    );
+}
+
+#[test]
+fn sorted_set_out_of_range() {
+    assert_eq!(
+        Ix::sorted_set(&[3i32, 11, -6, 4], -5, 10),
+        Err(OutOfRange::AboveMax(11))
+    );
+    assert_eq!(
+        Ix::sorted_set(&[3i32, -6, 11], -5, 10),
+        Err(OutOfRange::BelowMin(-6))
+    );
+}
@@ -0,0 +1,77 @@
+use ix_rs::{define_ix_newtype, Ix};
+use std::fmt::Debug;
+
+/// Check the derived methods of `T` against `range` and `index` for every subrange of `[lo, hi]`.
+fn check_window<T: Ix + Copy + Debug>(lo: T, hi: T) {
+    for min in Ix::range(lo, hi) {
+        for max in Ix::range(min, hi) {
+            let size = Ix::range_size(min, max);
+            assert_eq!(Ix::range(min, max).count(), size);
+            for (i, x) in Ix::range(min, max).enumerate() {
+                assert_eq!(x.index(min, max), i);
+                assert_eq!(x.index_u128(min, max), i as u128);
+                assert_eq!(Ix::from_index(i, min, max), x);
+                assert_eq!(Ix::select(x.rank(min, max), min, max), x);
+                assert_eq!(Ix::count_between(min, x, min, max), i + 1);
+                assert_eq!(Ix::count_between(x, max, min, max), size - i);
+                match x.successor(min, max) {
+                    Some(next) => {
+                        assert_eq!(next.index(min, max), i + 1);
+                        assert_eq!(next.predecessor(min, max), Some(x));
+                    }
+                    None => assert_eq!(i + 1, size),
+                }
+                match x.predecessor(min, max) {
+                    Some(prev) => assert_eq!(prev.successor(min, max), Some(x)),
+                    None => assert_eq!(i, 0),
+                }
+                assert_eq!(x.advance(size - 1 - i, min, max), Some(max));
+                assert_eq!(x.retreat(i, min, max), Some(min));
+            }
+            assert_eq!(Ix::from_index_checked(size, min, max), None);
+        }
+    }
+}
+
+/// Check a type at its lower edge, around its midpoint, and at its upper edge.
+macro_rules! check_all {
+    ($name: ident, $t: ty) => {
+        check_all!($name, $t, <$t>::MIN, <$t>::MAX, |x: $t| x, |x: $t| x);
+    };
+    ($name: ident, $t: ty, $min: expr, $max: expr, $wrap: expr, $unwrap: expr) => {
+        #[test]
+        fn $name() {
+            let (min, max): ($t, $t) = ($min, $max);
+            let wrap = $wrap;
+            let unwrap = $unwrap;
+            let mid = unwrap(min) / 2 + unwrap(max) / 2;
+            check_window(min, wrap(unwrap(min) + 15));
+            check_window(wrap(mid - 8), wrap(mid + 7));
+            check_window(wrap(unwrap(max) - 15), max);
+        }
+    };
+}
+
+check_all!(check_all_u8, u8);
+check_all!(check_all_u16, u16);
+check_all!(check_all_u32, u32);
+check_all!(check_all_u64, u64);
+check_all!(check_all_u128, u128);
+check_all!(check_all_usize, usize);
+check_all!(check_all_i8, i8);
+check_all!(check_all_i16, i16);
+check_all!(check_all_i32, i32);
+check_all!(check_all_i64, i64);
+check_all!(check_all_i128, i128);
+check_all!(check_all_isize, isize);
+
+define_ix_newtype!(Wrapped, u16);
+
+check_all!(
+    check_all_newtype,
+    Wrapped,
+    Wrapped(u16::MIN),
+    Wrapped(u16::MAX),
+    Wrapped,
+    |x: Wrapped| x.0
+);
@@ -0,0 +1,47 @@
+#![cfg(feature = "time")]
+
+use ix_rs::{BoundedIx, Ix};
+use time::{Date, Month};
+
+fn day(year: i32, month: Month, day: u8) -> Date {
+    Date::from_calendar_date(year, month, day).unwrap()
+}
+
+#[test]
+fn date_range_spans_leap_day() {
+    let (min, max) = (day(2024, Month::February, 27), day(2024, Month::March, 2));
+    assert_eq!(Ix::range_size(min, max), 5);
+    assert!(Ix::range(min, max).eq([
+        day(2024, Month::February, 27),
+        day(2024, Month::February, 28),
+        day(2024, Month::February, 29),
+        day(2024, Month::March, 1),
+        day(2024, Month::March, 2),
+    ]));
+    assert_eq!(day(2024, Month::February, 29).index(min, max), 2);
+    assert_eq!(day(2024, Month::March, 1).index(min, max), 3);
+    assert_eq!(Ix::from_index(3, min, max), day(2024, Month::March, 1));
+    let (min, max) = (day(2023, Month::February, 27), day(2023, Month::March, 2));
+    assert_eq!(Ix::range_size(min, max), 4);
+}
+
+#[test]
+fn date_navigation() {
+    let (min, max) = (day(2023, Month::December, 30), day(2024, Month::January, 2));
+    let last = day(2023, Month::December, 31);
+    assert_eq!(last.successor(min, max), Some(day(2024, Month::January, 1)));
+    assert_eq!(min.predecessor(min, max), None);
+    assert_eq!(min.advance(3, min, max), Some(max));
+    assert!(!day(2024, Month::January, 3).in_range(min, max));
+    assert_eq!(
+        Ix::range_size(day(2024, Month::January, 1), day(2024, Month::December, 31)),
+        366
+    );
+}
+
+#[test]
+fn date_bounds() {
+    assert_eq!(Date::full_range().next(), Some(Date::MIN));
+    let (min, max) = <Date as BoundedIx>::pad_bounds(Date::MIN, day(2000, Month::January, 1), 1);
+    assert_eq!((min, max), (Date::MIN, day(2000, Month::January, 2)));
+}
@@ -0,0 +1,47 @@
+use ix_rs::Ix;
+
+#[test]
+fn debug_dump_to_string() {
+    let mut out = String::new();
+    Ix::debug_dump_to((0u8, 'x'), (1, 'y'), &mut out).unwrap();
+    assert_eq!(
+        out,
+        "index=0 value=(0, 'x')\nindex=1 value=(0, 'y')\nindex=2 value=(1, 'x')\nindex=3 value=(1, 'y')\n"
+    );
+}
+
+#[test]
+fn debug_dump_to_single() {
+    let mut out = String::new();
+    Ix::debug_dump_to(-3i32, -3, &mut out).unwrap();
+    assert_eq!(out, "index=0 value=-3\n");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn debug_dump_runs() {
+    Ix::debug_dump(0u8, 2);
+}
+
+#[test]
+fn describe_format() {
+    assert_eq!(
+        Ix::describe(-5i32, 10).to_string(),
+        "[-5..=10] (16 elements)"
+    );
+    assert_eq!(
+        format!("{}", Ix::describe((0u8, 'a'), (1, 'c'))),
+        "[(0, 'a')..=(1, 'c')] (6 elements)"
+    );
+    assert_eq!(Ix::describe(4u8, 4).to_string(), "[4..=4] (1 element)");
+    assert_eq!(
+        Ix::describe(i128::MIN, i128::MAX).to_string(),
+        format!("[{}..={}] (overflows usize)", i128::MIN, i128::MAX)
+    );
+}
+
+#[test]
+#[should_panic(expected = "min is greater than max")]
+fn describe_unordered() {
+    Ix::describe(3i32, 2);
+}
@@ -0,0 +1,61 @@
+use ix_rs::{impl_ix_enum, Ix};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl_ix_enum!(Dir {
+    North,
+    East,
+    South,
+    West,
+});
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Shuffled {
+    B = 7,
+    A = 2,
+}
+
+impl_ix_enum!(Shuffled { A, B });
+
+#[test]
+fn enum_range() {
+    assert!(Ix::range(Dir::North, Dir::West).eq([Dir::North, Dir::East, Dir::South, Dir::West]));
+    assert!(Ix::range(Dir::East, Dir::East).eq([Dir::East]));
+    assert_eq!(Ix::range_size(Dir::East, Dir::West), 3);
+}
+
+#[test]
+fn enum_index_round_trip() {
+    for (i, dir) in Ix::range(Dir::North, Dir::West).enumerate() {
+        assert_eq!(dir.index(Dir::North, Dir::West), i);
+        assert_eq!(Ix::from_index(i, Dir::North, Dir::West), dir);
+    }
+    assert_eq!(Ix::from_index_checked(2, Dir::East, Dir::South), None);
+    assert_eq!(Dir::South.successor(Dir::North, Dir::West), Some(Dir::West));
+    assert!(!Dir::North.in_range(Dir::East, Dir::West));
+}
+
+#[test]
+fn enum_order_follows_listing() {
+    assert!(Dir::North < Dir::East && Dir::South < Dir::West);
+    assert!(Shuffled::A < Shuffled::B);
+    assert!(Ix::range(Shuffled::A, Shuffled::B).eq([Shuffled::A, Shuffled::B]));
+}
+
+#[test]
+#[should_panic(expected = "min is greater than max")]
+fn enum_unordered() {
+    let _ = Ix::range(Dir::West, Dir::North);
+}
+
+#[test]
+#[should_panic(expected = "index is outside range (< min)")]
+fn enum_index_below_min() {
+    Dir::North.index(Dir::East, Dir::West);
+}
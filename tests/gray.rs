@@ -0,0 +1,160 @@
+use ix_rs::{BoundedIx, Ix, NumericIx};
+
+fn adjacent_codes_differ_by_one_bit<T: NumericIx + Copy>(
+    min: T,
+    max: T,
+    bits: impl Fn(T) -> u128,
+) -> bool {
+    let codes: Vec<u128> = T::gray_range(min, max).map(bits).collect();
+    codes.windows(2).all(|w| (w[0] ^ w[1]).count_ones() == 1)
+}
+
+#[test]
+fn gray_range_unsigned() {
+    assert!(adjacent_codes_differ_by_one_bit(0u8, 255, u128::from));
+    assert!(adjacent_codes_differ_by_one_bit(0u32, 1023, u128::from));
+    assert!(adjacent_codes_differ_by_one_bit(
+        u64::MAX - 255,
+        u64::MAX,
+        u128::from
+    ));
+    assert_eq!(u8::gray_range(0, 255).count(), 256);
+}
+
+#[test]
+fn gray_range_signed() {
+    assert!(adjacent_codes_differ_by_one_bit(
+        i8::MIN,
+        i8::MAX,
+        |x| x as u8 as u128
+    ));
+    assert!(adjacent_codes_differ_by_one_bit(-512i16, 511, |x| x as u16 as u128));
+    assert!(adjacent_codes_differ_by_one_bit(-1i128, 0, |x| x as u128));
+}
+
+#[test]
+fn gray_round_trip() {
+    for x in u8::full_range() {
+        assert_eq!(NumericIx::from_gray(x.to_gray()), x);
+    }
+    for x in i8::full_range() {
+        assert_eq!(NumericIx::from_gray(x.to_gray()), x);
+    }
+    for x in [0, 1, u128::MAX, u128::MAX / 3, 1 << 127] {
+        assert_eq!(NumericIx::from_gray(x.to_gray()), x);
+    }
+    for x in [0, -1, i64::MIN, i64::MAX] {
+        assert_eq!(NumericIx::from_gray(x.to_gray()), x);
+    }
+}
+
+#[test]
+fn gray_codes_are_distinct() {
+    let mut codes: Vec<u8> = u8::gray_range(0, 255).collect();
+    codes.sort();
+    assert!(codes.into_iter().eq(0..=255));
+}
+
+#[test]
+fn sum_range_closed_form() {
+    assert_eq!(NumericIx::sum_range(1u32, 100), Some(5050));
+    assert_eq!(
+        NumericIx::sum_range(0u32, u32::MAX),
+        Some(i128::from(u32::MAX) * (1 << 31))
+    );
+    assert_eq!(
+        NumericIx::sum_range(u64::MIN, u64::MAX),
+        Some(i128::from(u64::MAX) * (1 << 63))
+    );
+    assert_eq!(NumericIx::sum_range(-5i8, 5), Some(0));
+    assert_eq!(NumericIx::sum_range(i128::MAX, i128::MAX), Some(i128::MAX));
+    assert_eq!(NumericIx::sum_range(i128::MAX - 1, i128::MAX), None);
+    assert_eq!(NumericIx::sum_range(i128::MIN, i128::MAX), Some(i128::MIN));
+    assert_eq!(NumericIx::sum_range(-(3i128 << 125), 3 << 125), Some(0));
+    assert_eq!(NumericIx::sum_range(i128::MIN, i128::MIN), Some(i128::MIN));
+    assert_eq!(NumericIx::sum_range(i128::MIN, i128::MIN + 1), None);
+    assert_eq!(
+        NumericIx::sum_range(-(3i128 << 125), (3 << 125) + 1),
+        Some((3 << 125) + 1)
+    );
+    for (min, max) in [
+        (0i32, 0),
+        (-7, 3),
+        (-100, -50),
+        (1000, 1999),
+        (i32::MAX - 10, i32::MAX),
+    ] {
+        let brute = (min..=max).map(i128::from).sum::<i128>();
+        assert_eq!(NumericIx::sum_range(min, max), Some(brute));
+    }
+}
+
+#[test]
+fn product_range_checked() {
+    assert_eq!(
+        NumericIx::product_range(1u64, 20),
+        Some(2432902008176640000)
+    );
+    assert_eq!(NumericIx::product_range(7u8, 7), Some(7));
+    assert_eq!(NumericIx::product_range(-4i16, -2), Some(-24));
+    assert_eq!(NumericIx::product_range(-200i16, 5), Some(0));
+    assert_eq!(NumericIx::product_range(1u128, 40), None);
+    for (min, max) in [(1i64, 10), (-6, -1), (3, 9)] {
+        let brute = (min..=max).map(i128::from).product::<i128>();
+        assert_eq!(NumericIx::product_range(min, max), Some(brute));
+    }
+}
+
+#[test]
+fn sum_range_large_unsigned() {
+    let min = i128::MAX as u128;
+    assert_eq!(NumericIx::sum_range(min, min + 2), None);
+    assert_eq!(NumericIx::sum_range(min, min), Some(i128::MAX));
+}
+
+#[test]
+fn hypercube_size_powers() {
+    assert_eq!(NumericIx::hypercube_size(-1i32, 1, 4), Some(81));
+    assert_eq!(NumericIx::hypercube_size(7u8, 7, 1000), Some(1));
+    assert_eq!(NumericIx::hypercube_size(-1i32, 1, 0), Some(1));
+    assert_eq!(NumericIx::hypercube_size(0u16, 65535, 4), None);
+    assert_eq!(NumericIx::hypercube_size(0u128, u128::MAX, 1), None);
+    assert_eq!(
+        NumericIx::hypercube_size(0u64, 1, usize::BITS - 1),
+        Some(1 << (usize::BITS - 1))
+    );
+    assert_eq!(NumericIx::hypercube_size(0u64, 1, usize::BITS), None);
+}
+
+#[test]
+fn reduce_sum_u128_matches_iteration() {
+    let iterative = Ix::reduce(1u64, 1_000_000, Some(0u128), |acc, x| {
+        acc?.checked_add(u128::from(x))
+    });
+    assert_eq!(NumericIx::reduce_sum_u128(1u64, 1_000_000), iterative);
+    assert_eq!(iterative, Some(500_000_500_000));
+    for (min, max) in [(-7i32, 3), (-3, 7), (-5, 5), (0, 0), (1000, 1999)] {
+        let brute = Ix::reduce(min, max, 0i128, |acc, x| acc + i128::from(x));
+        assert_eq!(
+            NumericIx::reduce_sum_u128(min, max),
+            u128::try_from(brute).ok()
+        );
+    }
+}
+
+#[test]
+fn reduce_sum_u128_large() {
+    assert_eq!(
+        NumericIx::reduce_sum_u128(u64::MIN, u64::MAX),
+        Some(u128::from(u64::MAX) * (1 << 63))
+    );
+    let min = i128::MAX as u128;
+    assert_eq!(NumericIx::reduce_sum_u128(min, min + 1), Some(u128::MAX));
+    assert_eq!(NumericIx::reduce_sum_u128(min, min + 2), None);
+    assert_eq!(
+        NumericIx::reduce_sum_u128(i128::MIN + 1, i128::MAX),
+        Some(0)
+    );
+    assert_eq!(NumericIx::reduce_sum_u128(i128::MIN, i128::MAX), None);
+    assert_eq!(NumericIx::reduce_sum_u128(-1i128, i128::MAX), None);
+}
@@ -0,0 +1,301 @@
+use ix_rs::{Bounds, Ix, Ix2};
+
+#[test]
+fn diagonal_square() {
+    assert!(Ix2::diagonal((0i32, 0i32), (2, 2)).eq([(0, 0), (1, 1), (2, 2)]));
+    assert!(Ix2::diagonal((5u8, 5u8), (5, 5)).eq([(5, 5)]));
+}
+
+#[test]
+fn diagonal_non_square() {
+    assert!(Ix2::diagonal((0i32, 0i32), (2, 4)).eq([(0, 0), (1, 1), (2, 2)]));
+    assert!(Ix2::diagonal((0i32, 0i32), (4, 2)).eq([(0, 0), (1, 1), (2, 2)]));
+}
+
+#[test]
+fn diagonal_in_range() {
+    let (min, max) = ((-1i8, 10u16), (3, 12));
+    for ix in Ix2::diagonal(min, max) {
+        assert!(ix.in_range(min, max));
+    }
+}
+
+#[test]
+fn boundary_square() {
+    let cells: Vec<_> = Ix2::boundary((0i32, 0i32), (3, 3)).collect();
+    assert_eq!(cells.len(), 12);
+    for (i, a) in cells.iter().enumerate() {
+        assert!(a.0 == 0 || a.0 == 3 || a.1 == 0 || a.1 == 3);
+        assert!(!cells[i + 1..].contains(a));
+    }
+}
+
+#[test]
+fn boundary_thin() {
+    assert!(Ix2::boundary((0i32, 0i32), (0, 4)).eq((0..=4).map(|c| (0, c))));
+    assert!(Ix2::boundary((0i32, 0i32), (4, 0)).eq((0..=4).map(|r| (r, 0))));
+    assert!(Ix2::boundary((7u8, 7u8), (7, 7)).eq([(7, 7)]));
+}
+
+#[test]
+fn boundary_two_rows() {
+    assert!(Ix2::boundary((0i32, 0i32), (1, 2)).eq([
+        (0, 0),
+        (0, 1),
+        (0, 2),
+        (1, 2),
+        (1, 1),
+        (1, 0)
+    ]));
+    assert!(Ix2::boundary((0i32, 0i32), (2, 1)).eq([
+        (0, 0),
+        (0, 1),
+        (1, 1),
+        (2, 1),
+        (2, 0),
+        (1, 0)
+    ]));
+}
+
+#[test]
+fn boundary_size() {
+    for rows in 0..6 {
+        for cols in 0..6 {
+            let expected = if rows == 0 || cols == 0 {
+                (rows + 1) * (cols + 1)
+            } else {
+                2 * (rows + cols)
+            };
+            assert_eq!(
+                Ix2::boundary((0i32, 0i32), (rows, cols)).count(),
+                expected as usize
+            );
+        }
+    }
+}
+
+#[test]
+fn neighbors4_counts() {
+    let (min, max) = ((0i32, 0i32), (4, 4));
+    assert_eq!((0, 0).neighbors4(min, max).count(), 2);
+    assert_eq!((4, 4).neighbors4(min, max).count(), 2);
+    assert_eq!((0, 2).neighbors4(min, max).count(), 3);
+    assert_eq!((2, 4).neighbors4(min, max).count(), 3);
+    assert_eq!((2, 2).neighbors4(min, max).count(), 4);
+    assert_eq!((3, 3).neighbors4((3, 3), (3, 3)).count(), 0);
+}
+
+#[test]
+fn neighbors8_counts() {
+    let (min, max) = ((0i32, 0i32), (4, 4));
+    assert_eq!((0, 0).neighbors8(min, max).count(), 3);
+    assert_eq!((4, 0).neighbors8(min, max).count(), 3);
+    assert_eq!((0, 2).neighbors8(min, max).count(), 5);
+    assert_eq!((2, 0).neighbors8(min, max).count(), 5);
+    assert_eq!((2, 2).neighbors8(min, max).count(), 8);
+}
+
+#[test]
+fn neighbors_are_adjacent() {
+    let (min, max) = ((0i32, 0i32), (4, 4));
+    for ix in Ix::range(min, max) {
+        for n in ix.neighbors8(min, max) {
+            assert!(n.in_range(min, max));
+            assert_ne!(n, ix);
+            assert!((n.0 - ix.0).abs() <= 1 && (n.1 - ix.1).abs() <= 1);
+        }
+        for n in ix.neighbors4(min, max) {
+            assert_eq!((n.0 - ix.0).abs() + (n.1 - ix.1).abs(), 1);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "index is outside range")]
+fn neighbors_outside() {
+    (5i32, 0i32).neighbors4((0, 0), (4, 4));
+}
+
+#[test]
+fn manhattan_distance_2d() {
+    let (min, max) = ((-5i32, -5i32), (5, 5));
+    assert_eq!((0, 0).manhattan_distance((2, 3), min, max), 5);
+    assert_eq!((2, 3).manhattan_distance((0, 0), min, max), 5);
+    assert_eq!((-5, 5).manhattan_distance((5, -5), min, max), 20);
+    assert_eq!((1, 1).manhattan_distance((1, 1), min, max), 0);
+    for a in Ix::range(min, max) {
+        for n in a.neighbors4(min, max) {
+            assert_eq!(a.manhattan_distance(n, min, max), 1);
+        }
+    }
+}
+
+#[test]
+fn manhattan_distance_scalar() {
+    assert_eq!(3i8.manhattan_distance(-7, -128, 127), 10);
+    assert_eq!(
+        i128::MIN.manhattan_distance(i128::MAX, i128::MIN, i128::MAX),
+        u128::MAX
+    );
+    assert_eq!(
+        '\u{D7FF}'.manhattan_distance('\u{E000}', '\0', char::MAX),
+        1
+    );
+}
+
+#[test]
+fn snake_range_order() {
+    let (min, max) = ((0i32, 0i32), (3, 4));
+    let snake: Vec<_> = Ix2::snake_range(min, max).collect();
+    let row_major: Vec<_> = Ix::range(min, max).collect();
+    let mut sorted = snake.clone();
+    sorted.sort();
+    assert_eq!(sorted, row_major);
+    for (row, cells) in snake.chunks(5).enumerate() {
+        let mut expected: Vec<_> = (0..=4).map(|col| (row as i32, col)).collect();
+        if row % 2 == 1 {
+            expected.reverse();
+        }
+        assert_eq!(cells, expected);
+    }
+    assert_ne!(snake, row_major);
+}
+
+#[test]
+fn snake_range_single_column() {
+    assert!(Ix2::snake_range((0i32, 0i32), (3, 0)).eq(Ix::range((0, 0), (3, 0))));
+}
+
+#[test]
+fn tiles_partition() {
+    let (min, max) = ((0i32, 0i32), (9, 9));
+    let tiles: Vec<_> = Ix2::tiles(min, max, (4, 4)).collect();
+    assert_eq!(tiles.len(), 9);
+    assert_eq!(tiles[0], Bounds::new((0, 0), (3, 3)));
+    assert_eq!(tiles[2], Bounds::new((0, 8), (3, 9)));
+    assert_eq!(tiles[8], Bounds::new((8, 8), (9, 9)));
+    let mut cells: Vec<_> = tiles
+        .iter()
+        .flat_map(|tile| Ix::range(*tile.min(), *tile.max()))
+        .collect();
+    assert_eq!(cells.len(), 100);
+    cells.sort();
+    assert!(cells.into_iter().eq(Ix::range(min, max)));
+}
+
+#[test]
+fn tiles_larger_than_range() {
+    assert!(Ix2::tiles((2u8, 'a'), (3, 'c'), (8, 8)).eq([Bounds::new((2, 'a'), (3, 'c'))]));
+}
+
+#[test]
+#[should_panic(expected = "tile size is zero")]
+fn tiles_zero() {
+    Ix2::tiles((0i32, 0i32), (9, 9), (0, 4));
+}
+
+#[test]
+fn morton_range_covers_range() {
+    for (rows, cols) in [(1, 1), (1, 7), (5, 3), (8, 8), (9, 13), (16, 2)] {
+        let (min, max) = ((-2i32, 10u16), (rows - 3, 9 + cols as u16));
+        let mut cells: Vec<_> = Ix2::morton_range(min, max).collect();
+        assert_eq!(cells.len(), Ix::range_size(min, max));
+        let codes: Vec<_> = cells
+            .iter()
+            .map(|cell| cell.morton_index(min, max))
+            .collect();
+        assert!(codes.windows(2).all(|pair| pair[0] < pair[1]));
+        cells.sort();
+        assert!(cells.into_iter().eq(Ix::range(min, max)));
+    }
+}
+
+#[test]
+fn morton_range_square() {
+    let codes: Vec<_> = Ix2::morton_range((0u8, 0u8), (3, 3))
+        .map(|cell| cell.morton_index((0, 0), (3, 3)))
+        .collect();
+    assert!(codes.into_iter().eq(0..16));
+}
+
+#[test]
+fn morton_range_thin() {
+    let max = (0u64, u64::from(u32::MAX));
+    let mut cells = Ix2::morton_range((0, 0), max);
+    assert_eq!(cells.nth(1000), Some((0, 1000)));
+    let max = (1u64 << 40, 0u64);
+    let mut cells = Ix2::morton_range((0, 0), max);
+    assert_eq!(cells.nth(1000), Some((1000, 0)));
+}
+
+#[test]
+fn morton_index_edges() {
+    let (min, max) = ((0u64, 0u64), (u64::MAX - 1, u64::MAX - 1));
+    assert_eq!(max.morton_index(min, max), u128::MAX - 3);
+    assert_eq!(
+        (0, u64::MAX - 1).morton_index(min, max),
+        (u128::MAX / 3) - 1
+    );
+}
+
+#[test]
+fn hilbert_range_adjacent() {
+    for k in 0..=5 {
+        let side = 1i32 << k;
+        let (min, max) = ((-3i32, 7u16), (side - 4, 6 + side as u16));
+        let cells: Vec<_> = Ix2::hilbert_range(min, max).collect();
+        assert_eq!(cells.len(), Ix::range_size(min, max));
+        for pair in cells.windows(2) {
+            let rows = pair[0].0.abs_diff(pair[1].0);
+            let cols = pair[0].1.abs_diff(pair[1].1);
+            assert_eq!(u32::from(cols) + rows, 1);
+        }
+        for (i, cell) in cells.iter().enumerate() {
+            assert_eq!(cell.hilbert_index(min, max), i as u128);
+            assert_eq!(Ix2::from_hilbert_index(i as u128, min, max), Some(*cell));
+        }
+        let mut sorted = cells;
+        sorted.sort();
+        assert!(sorted.into_iter().eq(Ix::range(min, max)));
+    }
+}
+
+#[test]
+fn hilbert_index_large() {
+    let (min, max) = ((0u64, 0u64), (u64::from(u32::MAX), u64::from(u32::MAX)));
+    for index in [0, 1, 12345, (1 << 64) - 1] {
+        let cell = Ix2::from_hilbert_index(index, min, max).unwrap();
+        assert_eq!(cell.hilbert_index(min, max), index);
+    }
+    assert_eq!(Ix2::from_hilbert_index(1 << 64, min, max), None);
+}
+
+#[test]
+#[should_panic(expected = "range is not a square whose side length is a power of two")]
+fn hilbert_range_not_square() {
+    Ix2::hilbert_range((0i32, 0i32), (3, 1));
+}
+
+#[test]
+#[should_panic(expected = "range is not a square whose side length is a power of two")]
+fn hilbert_range_not_power_of_two() {
+    Ix2::hilbert_range((0i32, 0i32), (2, 2));
+}
+
+#[test]
+fn staggered_range_parity() {
+    let staggered: Vec<_> = Ix2::staggered_range((3u8, 0u8), (6, 2)).collect();
+    assert_eq!(staggered.len(), 12);
+    assert!(staggered
+        .iter()
+        .map(|&(row, col, _)| (row, col))
+        .eq(Ix::range((3, 0), (6, 2))));
+    for (row, _, offset) in staggered {
+        assert_eq!(offset, (row - 3) % 2 == 1);
+    }
+    assert!(Ix2::staggered_range(('a', 0u8), ('c', 0)).eq([
+        ('a', 0, false),
+        ('b', 0, true),
+        ('c', 0, false)
+    ]));
+}
@@ -0,0 +1,250 @@
+use ix_rs::{Contains, Ix};
+use std::cmp::Reverse;
+
+#[test]
+fn merge_adjacent() {
+    assert_eq!(Ix::merge((0i32, 4), (5, 9)), Some((0, 9)));
+    assert_eq!(Ix::merge((5i32, 9), (0, 4)), Some((0, 9)));
+    assert!(Ix::is_mergeable((0i32, 4), (5, 9)));
+}
+
+#[test]
+fn merge_overlapping() {
+    assert_eq!(Ix::merge((0i32, 6), (3, 9)), Some((0, 9)));
+    assert_eq!(Ix::merge((0i32, 9), (3, 5)), Some((0, 9)));
+    assert_eq!(Ix::merge((3i32, 5), (3, 5)), Some((3, 5)));
+}
+
+#[test]
+fn merge_disjoint() {
+    assert_eq!(Ix::merge((0i32, 4), (6, 9)), None);
+    assert_eq!(Ix::merge((6i32, 9), (0, 4)), None);
+    assert!(!Ix::is_mergeable((0i32, 4), (6, 9)));
+}
+
+#[test]
+fn merge_type_edges() {
+    assert_eq!(Ix::merge((0u8, 254), (255, 255)), Some((0, 255)));
+    assert_eq!(
+        Ix::merge((u128::MAX, u128::MAX), (0, u128::MAX - 1)),
+        Some((0, u128::MAX))
+    );
+    assert_eq!(Ix::merge((i128::MIN, -1), (1, i128::MAX)), None);
+    assert_eq!(
+        Ix::merge((i128::MIN, 0), (1, i128::MAX)),
+        Some((i128::MIN, i128::MAX))
+    );
+}
+
+#[test]
+#[should_panic(expected = "min is greater than max")]
+fn merge_unordered() {
+    Ix::merge((4i32, 0), (5, 9));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn normalize_ranges_merges() {
+    assert_eq!(
+        Ix::normalize_ranges(&[(1i32, 3), (2, 5), (8, 10), (6, 7)]),
+        [(1, 10)]
+    );
+    assert_eq!(
+        Ix::normalize_ranges(&[(1i32, 3), (2, 4), (9, 10), (6, 7)]),
+        [(1, 4), (6, 7), (9, 10)]
+    );
+    assert_eq!(
+        Ix::normalize_ranges(&[(5u8, 5), (0, 255), (7, 9)]),
+        [(0, 255)]
+    );
+    assert_eq!(<i32 as Ix>::normalize_ranges(&[]), []);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn normalize_ranges_chars() {
+    assert_eq!(
+        Ix::normalize_ranges(&[
+            ('x', 'z'),
+            ('\u{D7FF}', '\u{D7FF}'),
+            ('\u{E000}', '\u{E001}'),
+            ('a', 'w')
+        ]),
+        [('a', 'z'), ('\u{D7FF}', '\u{E001}')]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn complement_gaps() {
+    assert_eq!(Ix::complement((0i32, 9), &[(2, 4)]), [(0, 1), (5, 9)]);
+    assert_eq!(Ix::complement((0i32, 9), &[]), [(0, 9)]);
+    assert_eq!(Ix::complement((0i32, 9), &[(0, 0), (9, 9)]), [(1, 8)]);
+    assert_eq!(
+        Ix::complement((0i32, 9), &[(6, 7), (2, 3), (3, 4)]),
+        [(0, 1), (5, 5), (8, 9)]
+    );
+    assert_eq!(Ix::complement((0i32, 9), &[(-3, 12)]), []);
+    assert_eq!(Ix::complement((0i32, 9), &[(-3, -1), (10, 12)]), [(0, 9)]);
+    assert_eq!(Ix::complement((0u8, 255), &[(0, 254)]), [(255, 255)]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn complement_chars() {
+    assert_eq!(
+        Ix::complement(('\u{D7FE}', '\u{E001}'), &[('\u{D7FF}', '\u{D7FF}')]),
+        [('\u{D7FE}', '\u{D7FE}'), ('\u{E000}', '\u{E001}')]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn difference_cases() {
+    assert_eq!(Ix::difference((0i32, 9), (3, 5)), [(0, 2), (6, 9)]);
+    assert_eq!(Ix::difference((0i32, 9), (20, 30)), [(0, 9)]);
+    assert_eq!(Ix::difference((0i32, 9), (-5, -1)), [(0, 9)]);
+    assert_eq!(Ix::difference((0i32, 9), (-5, 15)), []);
+    assert_eq!(Ix::difference((0i32, 9), (0, 9)), []);
+    assert_eq!(Ix::difference((0i32, 9), (-5, 2)), [(3, 9)]);
+    assert_eq!(Ix::difference((0i32, 9), (9, 9)), [(0, 8)]);
+    assert_eq!(
+        Ix::difference(('a', 'z'), ('c', 'x')),
+        [('a', 'b'), ('y', 'z')]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn symmetric_difference_cases() {
+    assert_eq!(
+        Ix::symmetric_difference((0i32, 5), (3, 9)),
+        [(0, 2), (6, 9)]
+    );
+    assert_eq!(
+        Ix::symmetric_difference((3i32, 9), (0, 5)),
+        [(0, 2), (6, 9)]
+    );
+    assert_eq!(Ix::symmetric_difference((0i32, 5), (0, 5)), []);
+    assert_eq!(
+        Ix::symmetric_difference((0i32, 9), (3, 5)),
+        [(0, 2), (6, 9)]
+    );
+    assert_eq!(
+        Ix::symmetric_difference((0i32, 2), (7, 9)),
+        [(0, 2), (7, 9)]
+    );
+    assert_eq!(Ix::symmetric_difference((0i32, 4), (5, 9)), [(0, 9)]);
+    assert_eq!(
+        Ix::symmetric_difference((0i32, 5), (5, 9)),
+        [(0, 4), (6, 9)]
+    );
+    assert_eq!(
+        Ix::symmetric_difference(('a', 'm'), ('h', 'z')),
+        [('a', 'g'), ('n', 'z')]
+    );
+}
+
+#[test]
+fn contains_points_and_ranges() {
+    assert!(Ix::contains((0i32, 9), Contains::Point(0)));
+    assert!(Ix::contains((0i32, 9), Contains::Point(9)));
+    assert!(!Ix::contains((0i32, 9), Contains::Point(10)));
+    assert!(Ix::contains((0i32, 9), Contains::Range(0, 9)));
+    assert!(Ix::contains((0i32, 9), Contains::Range(3, 3)));
+    assert!(!Ix::contains((0i32, 9), Contains::Range(-1, 3)));
+    assert!(!Ix::contains((0i32, 9), Contains::Range(12, 20)));
+    let outer = ((0u8, 0u8), (4, 4));
+    assert!(Ix::contains(outer, Contains::Range((1, 1), (3, 4))));
+    assert!(!Ix::contains(outer, Contains::Range((1, 1), (3, 5))));
+    assert!(!Ix::contains(outer, Contains::Point((2, 5))));
+}
+
+#[test]
+#[should_panic(expected = "min is greater than max")]
+fn contains_unordered_query() {
+    Ix::contains((0i32, 9), Contains::Range(5, 3));
+}
+
+#[test]
+fn overlap_size_one_dimensional() {
+    assert_eq!(Ix::overlap_size((0i32, 9), (5, 14)), 5);
+    assert_eq!(Ix::overlap_size((5i32, 14), (0, 9)), 5);
+    assert_eq!(Ix::overlap_size((0i32, 9), (3, 4)), 2);
+    assert_eq!(Ix::overlap_size((0i32, 9), (9, 12)), 1);
+    assert_eq!(Ix::overlap_size((0i32, 9), (10, 12)), 0);
+    assert_eq!(Ix::overlap_size((0i32, 9), (-8, -1)), 0);
+    assert_eq!(
+        Ix::overlap_size(('\u{D7F0}', '\u{E00F}'), ('\u{D7FE}', '\u{E001}')),
+        4
+    );
+    assert_eq!(
+        Ix::overlap_size((Reverse(9u8), Reverse(0)), (Reverse(12), Reverse(5))),
+        5
+    );
+}
+
+#[test]
+fn overlap_size_two_dimensional() {
+    let a = ((0u8, 0u8), (3, 3));
+    assert_eq!(Ix::overlap_size(a, ((2, 1), (5, 2))), 4);
+    assert_eq!(Ix::overlap_size(a, ((1, 1), (2, 2))), 4);
+    assert_eq!(Ix::overlap_size(a, ((3, 3), (5, 5))), 1);
+    assert_eq!(Ix::overlap_size(a, ((4, 0), (5, 3))), 0);
+    assert_eq!(Ix::overlap_size(a, ((0, 4), (3, 5))), 0);
+    assert_eq!(Ix::overlap_size(([0u8, 0], [3, 3]), ([2, 1], [5, 2])), 4);
+}
+
+#[test]
+fn overlap_size_checked_overflow() {
+    let full = (u128::MIN, u128::MAX);
+    assert_eq!(Ix::overlap_size_checked(full, full), None);
+    assert_eq!(Ix::overlap_size_checked(full, (5, 9)), Some(5));
+    let wide = ((0u64, 0u64), (u64::MAX, u64::MAX));
+    assert_eq!(Ix::overlap_size_checked(wide, wide), None);
+    assert_eq!(
+        Ix::overlap_size_checked(wide, ((0, 0), (u64::MAX, 0))),
+        usize::try_from(u128::from(u64::MAX) + 1).ok()
+    );
+    assert_eq!(
+        Ix::overlap_size_checked(((0u128, 0u8), (u128::MAX, 5)), ((0, 7), (u128::MAX, 9))),
+        Some(0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "range size too large")]
+fn overlap_size_overflow() {
+    Ix::overlap_size((i128::MIN, i128::MAX), (i128::MIN, i128::MAX));
+}
+
+#[test]
+#[should_panic(expected = "min is greater than max")]
+fn overlap_size_unordered() {
+    Ix::overlap_size((0i32, 9), (5, 3));
+}
+
+#[test]
+fn merge_boxes() {
+    let a = ((0u8, 0u8), (1, 3));
+    assert_eq!(Ix::merge(a, ((2, 0), (4, 3))), Some(((0, 0), (4, 3))));
+    assert_eq!(Ix::merge(a, ((0, 4), (1, 9))), Some(((0, 0), (1, 9))));
+    assert_eq!(Ix::merge(a, ((1, 1), (1, 2))), Some(a));
+    assert_eq!(Ix::merge(((1, 1), (1, 2)), a), Some(a));
+    assert_eq!(Ix::merge(a, ((3, 0), (4, 3))), None);
+    assert_eq!(Ix::merge(a, ((2, 0), (4, 2))), None);
+    assert_eq!(Ix::merge(((0u8, 0u8), (1, 1)), ((2, 2), (3, 3))), None);
+    assert!(!Ix::is_mergeable(((0u8, 0u8), (1, 1)), ((1, 2), (3, 3))));
+    assert_eq!(
+        Ix::merge(([0u8, 0], [1, 3]), ([2, 0], [4, 3])),
+        Some(([0, 0], [4, 3]))
+    );
+    assert_eq!(Ix::merge(([0u8, 0], [1, 1]), ([2, 2], [3, 3])), None);
+    assert_eq!(
+        Ix::merge(
+            (Reverse((1u8, 3u8)), Reverse((0, 0))),
+            (Reverse((4, 3)), Reverse((2, 0)))
+        ),
+        Some((Reverse((4, 3)), Reverse((0, 0))))
+    );
+}
@@ -0,0 +1,588 @@
+use ix_rs::{Ix, NumericIx, OutOfRange};
+
+#[test]
+fn advance_within_range() {
+    assert_eq!(3i32.advance(0, -10, 10), Some(3));
+    assert_eq!(3i32.advance(4, -10, 10), Some(7));
+    assert_eq!((-10i32).advance(20, -10, 10), Some(10));
+    assert_eq!((-10i32).advance(21, -10, 10), None);
+    assert_eq!(10i32.advance(1, -10, 10), None);
+}
+
+#[test]
+fn advance_at_type_edges() {
+    assert_eq!(
+        0u128.advance(usize::MAX, 0, u128::MAX),
+        Some(usize::MAX as u128)
+    );
+    assert_eq!((u128::MAX - 1).advance(1, 0, u128::MAX), Some(u128::MAX));
+    assert_eq!(u128::MAX.advance(1, 0, u128::MAX), None);
+    assert_eq!(i8::MIN.advance(255, i8::MIN, i8::MAX), Some(i8::MAX));
+    assert_eq!(i8::MIN.advance(usize::MAX, i8::MIN, i8::MAX), None);
+}
+
+#[test]
+fn advance_composite() {
+    let (min, max) = ((0u8, 0u8), (2, 2));
+    assert_eq!((0, 2).advance(1, min, max), Some((1, 0)));
+    assert_eq!((0, 0).advance(8, min, max), Some((2, 2)));
+    assert_eq!((0, 1).advance(8, min, max), None);
+    assert_eq!('\u{D7FF}'.advance(2, '\0', char::MAX), Some('\u{E001}'));
+}
+
+#[test]
+fn retreat_past_min() {
+    assert_eq!(3i32.retreat(13, -10, 10), Some(-10));
+    assert_eq!(3i32.retreat(14, -10, 10), None);
+    assert_eq!(
+        u128::MAX.retreat(usize::MAX, 0, u128::MAX),
+        Some(u128::MAX - usize::MAX as u128)
+    );
+    assert_eq!((1u8, 0u8).retreat(1, (0, 0), (2, 2)), Some((0, 2)));
+    assert_eq!((0u8, 2u8).retreat(3, (0, 0), (2, 2)), None);
+}
+
+#[test]
+fn index_u128_at_extremes() {
+    assert_eq!(u128::MAX.index_u128(0, u128::MAX), u128::MAX);
+    assert_eq!((u128::MAX - 1).index_u128(1, u128::MAX), u128::MAX - 2);
+    assert_eq!(i128::MAX.index_u128(i128::MIN, i128::MAX), u128::MAX);
+    assert_eq!(i128::MIN.index_u128(i128::MIN, i128::MAX), 0);
+    assert_eq!((-1i128).index_u128(i128::MIN, 0), u128::MAX >> 1);
+    assert_eq!(
+        i64::MAX.index_u128(i64::MIN, i64::MAX),
+        u128::from(u64::MAX)
+    );
+}
+
+#[test]
+fn index_u128_agrees_with_index() {
+    assert_eq!(7u8.index_u128(3, 9), 4);
+    assert_eq!('\u{E000}'.index_u128('\u{D7FF}', '\u{E001}'), 1);
+    assert_eq!((1u8, 2u128).index_u128((0, 0), (2, 3)), 6);
+    assert_eq!(
+        (1u8, 2u128).index_u128((0, 0), (2, 1 << 100)),
+        (1 << 100) + 3
+    );
+    assert_eq!(
+        (0u8, u128::MAX).index_u128((0, 0), (0, u128::MAX)),
+        u128::MAX
+    );
+}
+
+#[test]
+#[should_panic(expected = "index too large")]
+fn index_u128_tuple_overflow() {
+    (1u8, 0u128).index_u128((0, 0), (1, u128::MAX));
+}
+
+#[test]
+fn count_leading_and_trailing() {
+    assert_eq!(Ix::count_leading(0i32, 9, |x| x % 2 == 0), 1);
+    assert_eq!(Ix::count_trailing(0i32, 9, |x| x % 2 == 1), 1);
+    assert_eq!(Ix::count_leading(0i32, 9, |_| true), 10);
+    assert_eq!(Ix::count_trailing(0i32, 9, |_| true), 10);
+    assert_eq!(Ix::count_leading(0i32, 9, |_| false), 0);
+    assert_eq!(Ix::count_trailing(0i32, 9, |_| false), 0);
+    assert_eq!(
+        Ix::count_trailing((0u8, 0u8), (2, 2), |(row, _)| *row == 2),
+        3
+    );
+    assert_eq!(
+        Ix::count_leading('\u{D7FE}', '\u{E001}', |c| *c < '\u{E001}'),
+        3
+    );
+}
+
+#[test]
+fn counted_range_remaining() {
+    let mut range = Ix::counted_range(-2i32, 2).unwrap();
+    assert_eq!(range.remaining(), Ix::range_size(-2i32, 2));
+    assert_eq!(range.len(), 5);
+    for (ix, remaining) in (-2..=2).zip((0..5).rev()) {
+        assert_eq!(range.next(), Some(ix));
+        assert_eq!(range.remaining(), remaining);
+    }
+    assert_eq!(range.next(), None);
+    assert_eq!(range.remaining(), 0);
+}
+
+#[test]
+fn counted_range_composite() {
+    let range = Ix::counted_range((0u8, 'a'), (1, 'c')).unwrap();
+    assert_eq!(range.remaining(), 6);
+    assert_eq!(range.count(), 6);
+    assert!(Ix::counted_range((0u64, 0u64), (u64::MAX, u64::MAX)).is_none());
+}
+
+#[test]
+fn index_be_bytes_fixed_width() {
+    let mut expected = [0u8; 16];
+    expected[12..].copy_from_slice(&[0x01, 0x02, 0x03, 0x04]);
+    assert_eq!(0x0102_0304u64.index_be_bytes(0, u64::MAX), expected);
+    assert_eq!(0x0102_0304usize.index_be_bytes(0, usize::MAX), expected);
+    assert_eq!(0x0102_0304u32.index_be_bytes(0, u32::MAX), expected);
+    assert_eq!(
+        (i64::MIN + 0x0102_0304).index_be_bytes(i64::MIN, i64::MAX),
+        expected
+    );
+}
+
+#[test]
+fn index_be_bytes_round_trip() {
+    let values = [i128::MIN, -1, 0, 1, i128::MAX];
+    for x in values {
+        let bytes = x.index_be_bytes(i128::MIN, i128::MAX);
+        assert_eq!(
+            Ix::from_index_be_bytes(bytes, i128::MIN, i128::MAX),
+            Some(x)
+        );
+    }
+    let (min, max) = ((0u8, 'a'), (9, 'z'));
+    for x in Ix::range(min, max) {
+        assert_eq!(
+            Ix::from_index_be_bytes(x.index_be_bytes(min, max), min, max),
+            Some(x)
+        );
+    }
+    assert_eq!(Ix::from_index_be_bytes([0xFF; 16], min, max), None);
+    assert_eq!(Ix::from_index_be_bytes(10u128.to_be_bytes(), 0u8, 9), None);
+}
+
+#[test]
+fn align_to_stride() {
+    for x in 0i32..=31 {
+        assert_eq!(x.align_down(0, 31, 8), x / 8 * 8);
+        assert_eq!(x.align_up(0, 31, 8), ((x + 7) / 8 * 8).min(31));
+    }
+    for x in [0i32, 8, 16, 24] {
+        assert_eq!(x.align_down(0, 31, 8), x);
+        assert_eq!(x.align_up(0, 31, 8), x);
+    }
+    assert_eq!(5i32.align_down(3, 20, 4), 3);
+    assert_eq!(5i32.align_up(3, 20, 4), 7);
+}
+
+#[test]
+fn align_edges() {
+    assert_eq!(u128::MAX.align_down(0, u128::MAX, 16), u128::MAX - 15);
+    assert_eq!((u128::MAX - 3).align_up(0, u128::MAX, 16), u128::MAX);
+    assert_eq!(i8::MAX.align_up(i8::MIN, i8::MAX, usize::MAX), i8::MAX);
+    assert_eq!((1u8, 3u8).align_down((0, 0), (3, 3), 8), (0, 0));
+    assert_eq!((1u8, 3u8).align_up((0, 0), (3, 3), 8), (2, 0));
+    assert_eq!('e'.align_up('a', 'f', 3), 'f');
+}
+
+#[test]
+fn is_aligned_offsets() {
+    assert!(0i32.is_aligned(0, 31, 8));
+    assert!(8i32.is_aligned(0, 31, 8));
+    assert!(!3i32.is_aligned(0, 31, 8));
+    for x in 0i32..=31 {
+        assert_eq!(x.is_aligned(0, 31, 8), x.align_down(0, 31, 8) == x);
+    }
+    assert!(11i32.is_aligned(3, 20, 4));
+    assert!((2u8, 0u8).is_aligned((0, 0), (3, 3), 8));
+    assert!(!(1u8, 3u8).is_aligned((0, 0), (3, 3), 8));
+    assert!(u128::MAX.is_aligned(1, u128::MAX, 2));
+}
+
+#[test]
+#[should_panic(expected = "alignment is zero")]
+fn is_aligned_zero() {
+    4i32.is_aligned(0, 31, 0);
+}
+
+#[test]
+#[should_panic(expected = "alignment is zero")]
+fn align_zero() {
+    3i32.align_down(0, 31, 0);
+}
+
+#[test]
+fn try_for_each_index_breaks() {
+    use std::ops::ControlFlow;
+    let mut visited = Vec::new();
+    let result = Ix::try_for_each_index((0u8, 0u8), (3, 3), |(row, col)| {
+        visited.push((row, col));
+        if row * col == 2 {
+            ControlFlow::Break(row * 4 + col)
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    let mut expected = Vec::new();
+    let mut found = None;
+    for row in 0..=3u8 {
+        for col in 0..=3u8 {
+            expected.push((row, col));
+            if row * col == 2 {
+                found = Some(row * 4 + col);
+                break;
+            }
+        }
+        if found.is_some() {
+            break;
+        }
+    }
+    assert_eq!(result, ControlFlow::Break(found.unwrap()));
+    assert_eq!(visited, expected);
+}
+
+#[test]
+fn try_for_each_index_continues() {
+    use std::ops::ControlFlow;
+    let mut count = 0;
+    let result: ControlFlow<()> = Ix::try_for_each_index('a', 'z', |_| {
+        count += 1;
+        ControlFlow::Continue(())
+    });
+    assert_eq!(result, ControlFlow::Continue(()));
+    assert_eq!(count, 26);
+}
+
+#[test]
+fn try_fold_indices_stops_at_error() {
+    let mut visited = Vec::new();
+    let result = Ix::try_fold_indices((0u8, 0u8), (2, 2), Vec::new(), |mut acc, (row, col)| {
+        visited.push((row, col));
+        if row == 1 && col == 1 {
+            return Err("occupied");
+        }
+        acc.push(row * 3 + col);
+        Ok(acc)
+    });
+    assert_eq!(result, Err("occupied"));
+    assert_eq!(visited, [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1)]);
+}
+
+#[test]
+fn try_fold_indices_completes() {
+    let result: Result<_, ()> = Ix::try_fold_indices(-3i32, 3, 0, |acc, x| Ok(acc + x));
+    assert_eq!(result, Ok(0));
+}
+
+#[test]
+fn index_diff_signed() {
+    assert_eq!(3i32.index_diff(7, 0, 10), -4);
+    for (a, b) in [(0i32, 10), (3, 7), (5, 5), (-2, 9)] {
+        assert_eq!(a.index_diff(b, -5, 10), -b.index_diff(a, -5, 10));
+        assert_eq!(a.index_diff(b, -5, 10), i128::from(a - b));
+    }
+    assert_eq!('\u{E000}'.index_diff('\u{D7FF}', '\0', char::MAX), 1);
+}
+
+#[test]
+fn index_diff_extremes() {
+    let (min, max) = (0u128, u128::MAX);
+    assert_eq!((1u128 << 127).checked_index_diff(0, min, max), None);
+    assert_eq!(
+        0u128.checked_index_diff(1 << 127, min, max),
+        Some(i128::MIN)
+    );
+    assert_eq!(
+        ((1u128 << 127) - 1).checked_index_diff(0, min, max),
+        Some(i128::MAX)
+    );
+    assert_eq!(
+        i128::MIN.checked_index_diff(i128::MAX, i128::MIN, i128::MAX),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "difference too large")]
+fn index_diff_overflow() {
+    u128::MAX.index_diff(0, 0, u128::MAX);
+}
+
+#[test]
+fn span_ratio_endpoints() {
+    let ratios: Vec<_> = [0i32, 2, 4].map(|x| x.span_ratio(0, 4)).into();
+    assert_eq!(ratios, [0.0, 0.5, 1.0]);
+    assert_eq!(7u8.span_ratio(7, 7), 0.0);
+    assert_eq!(u128::MAX.span_ratio(0, u128::MAX), 1.0);
+    assert_eq!(i128::MIN.span_ratio(i128::MIN, i128::MAX), 0.0);
+    assert_eq!((1u8, 1u8).span_ratio((0, 0), (2, 2)), 0.5);
+}
+
+#[test]
+fn check_in_range_sides() {
+    assert_eq!(3i32.check_in_range(-5, 5), Ok(()));
+    assert_eq!((-5i32).check_in_range(-5, 5), Ok(()));
+    assert_eq!(5i32.check_in_range(-5, 5), Ok(()));
+    assert_eq!((-6i32).check_in_range(-5, 5), Err(OutOfRange::BelowMin(-6)));
+    assert_eq!(6i32.check_in_range(-5, 5), Err(OutOfRange::AboveMax(6)));
+    assert_eq!('A'.check_in_range('a', 'z'), Err(OutOfRange::BelowMin('A')));
+    let err = (1u8, 7u8).check_in_range((0, 0), (3, 3)).unwrap_err();
+    assert_eq!(err, OutOfRange::AboveMax((1, 7)));
+    assert_eq!(err.into_inner(), (1, 7));
+    assert_eq!(
+        OutOfRange::BelowMin(0).to_string(),
+        "index is outside range (< min)"
+    );
+}
+
+#[test]
+fn cycle_range_repeats() {
+    let size = Ix::range_size(-2i32, 2);
+    let cycled: Vec<_> = Ix::cycle_range(-2i32, 2).take(2 * size + 1).collect();
+    assert_eq!(cycled, [-2, -1, 0, 1, 2, -2, -1, 0, 1, 2, -2]);
+    assert!(Ix::cycle_range(7u8, 7).take(4).eq([7; 4]));
+    assert!(Ix::cycle_range(254u8, 255)
+        .take(5)
+        .eq([254, 255, 254, 255, 254]));
+    let frames = ["a", "b", "c", "d"];
+    let tagged: Vec<_> = Ix::cycle_range((0u8, 0u8), (0, 1)).zip(frames).collect();
+    assert_eq!(
+        tagged,
+        [((0, 0), "a"), ((0, 1), "b"), ((0, 0), "c"), ((0, 1), "d")]
+    );
+}
+
+#[test]
+fn partition_point_binary_search() {
+    assert_eq!(Ix::partition_point(0i32, 99, |x| *x < 50), Some(50));
+    assert_eq!(Ix::partition_point(0i32, 99, |_| false), Some(0));
+    assert_eq!(Ix::partition_point(0i32, 99, |_| true), None);
+    assert_eq!(Ix::partition_point(5u8, 5, |x| *x < 5), Some(5));
+    assert_eq!(Ix::partition_point(5u8, 5, |x| *x <= 5), None);
+    assert_eq!(
+        Ix::partition_point(0u64, 1 << 20, |x| *x < 300_000),
+        Some(300_000)
+    );
+    assert_eq!(
+        Ix::partition_point((0u8, 0u8), (9, 9), |&(a, b)| a < 4 || (a == 4 && b < 7)),
+        Some((4, 7))
+    );
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn partition_point_logarithmic() {
+    assert_eq!(
+        Ix::partition_point(0u64, u32::MAX.into(), |x| *x < 3_000_000_000),
+        Some(3_000_000_000)
+    );
+    let mut calls = 0;
+    Ix::partition_point(0i32, 1023, |x| {
+        calls += 1;
+        *x < 600
+    });
+    assert!(calls <= 11);
+}
+
+#[test]
+fn range_within_clips() {
+    assert!(Ix::range_within(-5i32, 3, 0, 9).eq(0..=3));
+    assert!(Ix::range_within(4i32, 100, 0, 9).eq(4..=9));
+    assert!(Ix::range_within(-5i32, 100, 0, 9).eq(0..=9));
+    assert!(Ix::range_within(6i32, 2, 0, 9).eq([]));
+    assert!(Ix::range_within(20i32, 30, 0, 9).eq([9]));
+    assert!(Ix::range_within('a', 'c', 'b', 'z').eq(['b', 'c']));
+    assert!(Ix::range_within((1u8, 2u8), (2, 0), (0, 0), (3, 2)).eq([(1, 2), (2, 0)]));
+}
+
+#[test]
+fn is_single_ranges() {
+    assert!(Ix::is_single(5i32, 5));
+    assert!(!Ix::is_single(5i32, 6));
+    assert!(Ix::is_multi(i128::MIN, i128::MAX));
+    assert!(!Ix::is_single((0u8, 0u8), (0, 1)));
+    assert!(Ix::is_single((3u8, 'x'), (3, 'x')));
+    assert!(Ix::is_single((), ()));
+    assert!(Ix::is_single([7u8; 3], [7; 3]));
+}
+
+#[test]
+#[should_panic(expected = "min is greater than max")]
+fn is_single_unordered() {
+    Ix::is_single(6i32, 5);
+}
+
+#[test]
+fn converging_pairs_meet_in_middle() {
+    assert!(Ix::converging_pairs(0i32, 5).eq([(0, 5), (1, 4), (2, 3)]));
+    assert!(Ix::converging_pairs(0i32, 4).eq([(0, 4), (1, 3), (2, 2)]));
+    assert!(Ix::converging_pairs(3u8, 3).eq([(3, 3)]));
+    assert!(Ix::converging_pairs(254u8, 255).eq([(254, 255)]));
+    assert_eq!(Ix::converging_pairs(0u8, 255).count(), 128);
+    assert!(Ix::converging_pairs((0u8, 0u8), (1, 1)).eq([((0, 0), (1, 1)), ((0, 1), (1, 0))]));
+    let word = b"racecar";
+    assert!(Ix::converging_pairs(0usize, word.len() - 1).all(|(i, j)| word[i] == word[j]));
+}
+
+#[test]
+fn index_mod_matches_manual() {
+    for x in -10i32..=10 {
+        assert_eq!(x.index_mod(-10, 10, 4), x.index(-10, 10) % 4);
+    }
+    for ix in Ix::range((0u8, 0u8), (2, 4)) {
+        assert_eq!(
+            ix.index_mod((0, 0), (2, 4), 4),
+            ix.index((0, 0), (2, 4)) % 4
+        );
+    }
+    assert_eq!(i128::MAX.index_mod(i128::MIN, i128::MAX, 4), 3);
+    assert_eq!(7u8.index_mod(0, 255, 1), 0);
+}
+
+#[test]
+#[should_panic(expected = "table size is zero")]
+fn index_mod_zero_table() {
+    3i32.index_mod(0, 9, 0);
+}
+
+#[test]
+fn saturating_steps_stick_at_ends() {
+    let (min, max) = ((0u8, 0u8), (1, 2));
+    let mut ix = min;
+    let mut visited = vec![ix];
+    for _ in 0..10 {
+        ix = ix.successor_saturating(min, max);
+        visited.push(ix);
+    }
+    assert!(visited[..6].iter().copied().eq(Ix::range(min, max)));
+    assert!(visited[6..].iter().all(|&ix| ix == max));
+    for _ in 0..10 {
+        ix = ix.predecessor_saturating(min, max);
+    }
+    assert_eq!(ix, min);
+    assert_eq!(i8::MAX.successor_saturating(i8::MIN, i8::MAX), i8::MAX);
+    assert_eq!(i8::MIN.predecessor_saturating(i8::MIN, i8::MAX), i8::MIN);
+}
+
+#[test]
+fn iter_with_bounds_triples() {
+    assert!(Ix::iter_with_bounds(3u8, 5).eq([(3, 3, 5), (4, 3, 5), (5, 3, 5)]));
+    let indices: Vec<_> = Ix::iter_with_bounds((0u8, 'a'), (1, 'b'))
+        .map(|(ix, min, max)| ix.index(min, max))
+        .collect();
+    assert_eq!(indices, [0, 1, 2, 3]);
+}
+
+#[test]
+fn first_last_nth() {
+    let (min, max) = ((1u8, -2i32), (4, 3));
+    assert_eq!(Ix::first(min, max), (1, -2));
+    assert_eq!(Ix::last(min, max), (4, 3));
+    assert_eq!(Ix::range(min, max).next(), Some(Ix::first(min, max)));
+    assert_eq!(Ix::range(min, max).last(), Some(Ix::last(min, max)));
+    assert_eq!(Ix::nth(7, min, max), Some((2, -1)));
+    assert_eq!(Ix::nth(24, min, max), None);
+    assert_eq!(Ix::nth(0, 'q', 'q'), Some('q'));
+}
+
+#[test]
+fn scan_indices_running_state() {
+    let products: Vec<_> = Ix::scan_indices(1u64, 6, 1u64, |acc, x| {
+        *acc *= x;
+        Some(*acc)
+    })
+    .collect();
+    assert_eq!(products, [1, 2, 6, 24, 120, 720]);
+    let labels: Vec<_> = Ix::scan_indices((0u8, 0u8), (1, 1), 0usize, |count, ix| {
+        *count += 1;
+        Some((*count, ix))
+    })
+    .collect();
+    assert_eq!(labels, [(1, (0, 0)), (2, (0, 1)), (3, (1, 0)), (4, (1, 1))]);
+    let prefix = Ix::scan_indices(0i32, 9, 0, |sum, x| {
+        *sum += x;
+        (*sum < 10).then_some(*sum)
+    });
+    assert!(prefix.eq([0, 1, 3, 6]));
+}
+
+#[test]
+fn assert_monotone_accepts_partitioned() {
+    Ix::assert_monotone(0i32, 99, |x| *x < 50);
+    Ix::assert_monotone(0i32, 99, |_| true);
+    Ix::assert_monotone(0i32, 99, |_| false);
+    Ix::assert_monotone((0u8, 0u8), (3, 3), |&(a, b)| a < 2 || (a == 2 && b < 1));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "predicate is true at position 3 after being false at position 2")]
+fn assert_monotone_rejects_unpartitioned() {
+    Ix::assert_monotone(0i32, 9, |x| *x != 2);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "predicate is true at position 3 after being false at position 2")]
+fn partition_point_checks_monotone() {
+    Ix::partition_point(0i32, 9, |x| *x != 2);
+}
+
+#[test]
+fn linspace_ticks() {
+    let ticks: Vec<_> = NumericIx::linspace(0i32, 10, 6).collect();
+    assert_eq!(ticks, [0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+    assert_eq!(NumericIx::linspace(0i32, 10, 0).count(), 0);
+    assert!(NumericIx::linspace(-3i32, 7, 1).eq([-3.0]));
+    assert!(NumericIx::linspace(-3i32, 7, 2).eq([-3.0, 7.0]));
+    assert!(NumericIx::linspace(5i32, 15, 3).eq([5.0, 10.0, 15.0]));
+    assert!(NumericIx::linspace(5u8, 5, 3).eq([5.0, 5.0, 5.0]));
+    assert_eq!(NumericIx::linspace(0i32, 10, 4).len(), 4);
+    assert!(NumericIx::linspace(i128::MIN, i128::MAX, 2).eq([i128::MIN as f64, i128::MAX as f64]));
+}
+
+#[test]
+fn linspace_positions_offsets() {
+    assert!(Ix::linspace_positions(-3i32, 7, 1).eq([0.0]));
+    assert!(Ix::linspace_positions(5i32, 15, 3).eq([0.0, 5.0, 10.0]));
+    assert!(Ix::linspace_positions('a', 'e', 3).eq([0.0, 2.0, 4.0]));
+    assert!(Ix::linspace_positions((0u8, 0u8), (1, 1), 2).eq([0.0, 3.0]));
+    assert_eq!(
+        Ix::linspace_positions(0u128, u128::MAX, 2).last(),
+        Some(u128::MAX as f64)
+    );
+}
+
+#[test]
+fn from_index_as_offsets() {
+    let offset: u32 = 7u8.index_as(3, 200).unwrap();
+    assert_eq!(Ix::from_index_as(offset, 3u8, 200), Some(7));
+    assert_eq!(Ix::from_index_as(197u32, 3u8, 200), Some(200));
+    assert_eq!(Ix::from_index_as(198u32, 3u8, 200), None);
+    assert_eq!(Ix::from_index_as(u32::MAX, 3u8, 200), None);
+    assert_eq!(Ix::from_index_as(-1i64, 3u8, 200), None);
+}
+
+#[test]
+fn count_between_ranks() {
+    assert_eq!(Ix::count_between(-2i8, 2, -3, 3), 5);
+    assert_eq!(Ix::count_between(3i8, -3, -3, 3), 0);
+    assert_eq!(Ix::count_between('x', 'z', 'a', 'z'), 3);
+    assert_eq!(Ix::count_between((0u8, 2u8), (1, 1), (0, 0), (2, 2)), 3);
+    assert_eq!(
+        Ix::count_between(usize::MIN, usize::MAX - 1, 0, usize::MAX),
+        usize::MAX
+    );
+}
+
+#[test]
+fn try_sum_indices_totals() {
+    let points = [1000u32, 1003, 1999, 1500, 1003];
+    assert_eq!(
+        Ix::try_sum_indices(1000u32, 2000, points),
+        Some(3 + 999 + 500 + 3)
+    );
+    assert_eq!(Ix::try_sum_indices(1000u32, 2000, []), Some(0));
+    assert_eq!(Ix::try_sum_indices(1000u32, 2000, [1500, 999]), None);
+}
+
+#[test]
+fn try_sum_indices_wide() {
+    assert_eq!(
+        Ix::try_sum_indices(0u128, u128::MAX, [u128::MAX]),
+        Some(u128::MAX)
+    );
+    assert_eq!(
+        Ix::try_sum_indices(i128::MIN, i128::MAX, [0, -1]),
+        Some(u128::MAX)
+    );
+    assert_eq!(Ix::try_sum_indices(0u128, u128::MAX, [u128::MAX, 1]), None);
+}
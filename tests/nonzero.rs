@@ -0,0 +1,113 @@
+use core::num::{NonZeroU128, NonZeroU8, NonZeroUsize};
+use ix_rs::{BoundedIx, Ix};
+
+fn nz(n: usize) -> NonZeroUsize {
+    NonZeroUsize::new(n).unwrap()
+}
+
+#[test]
+fn nonzero_usize_window() {
+    assert!(Ix::range(nz(3), nz(6)).eq([3, 4, 5, 6].map(nz)));
+    assert_eq!(nz(5).index(nz(3), nz(6)), 2);
+    assert_eq!(Ix::range_size(nz(3), nz(6)), 4);
+    assert_eq!(Ix::from_index_checked(3, nz(3), nz(6)), Some(nz(6)));
+    assert_eq!(Ix::from_index_checked(4, nz(3), nz(6)), None);
+    assert!(!nz(7).in_range(nz(3), nz(6)));
+}
+
+#[test]
+fn nonzero_usize_navigation() {
+    assert_eq!(nz(1).predecessor(nz(1), nz(4)), None);
+    assert_eq!(nz(2).predecessor(nz(1), nz(4)), Some(nz(1)));
+    assert_eq!(nz(4).successor(nz(1), nz(4)), None);
+    assert_eq!(nz(1).advance(3, nz(1), nz(4)), Some(nz(4)));
+    assert_eq!(nz(4).retreat(4, nz(1), nz(4)), None);
+}
+
+#[test]
+fn nonzero_usize_full() {
+    assert_eq!(
+        Ix::range_size_checked(NonZeroUsize::MIN, NonZeroUsize::MAX),
+        Some(usize::MAX)
+    );
+    assert_eq!(
+        NonZeroUsize::MAX.index(NonZeroUsize::MIN, NonZeroUsize::MAX),
+        usize::MAX - 1
+    );
+}
+
+#[test]
+fn nonzero_usize_bounded() {
+    assert_eq!(BoundedIx::pad_bounds(nz(2), nz(5), 3), (nz(1), nz(8)));
+    assert_eq!(
+        BoundedIx::shrink_bounds(nz(2), nz(5), 1),
+        Some((nz(3), nz(4)))
+    );
+    assert_eq!(BoundedIx::shrink_bounds(nz(2), nz(5), 2), None);
+}
+
+#[test]
+fn range_size_nonzero_sizes() {
+    assert_eq!(Ix::range_size_nonzero(5i32, 5), NonZeroUsize::new(1));
+    assert_eq!(Ix::range_size_nonzero(-5i32, 5), NonZeroUsize::new(11));
+    assert_eq!(
+        Ix::range_size_nonzero((0u8, 0u8), (255, 255)),
+        NonZeroUsize::new(65536)
+    );
+    assert_eq!(Ix::range_size_nonzero(i128::MIN, i128::MAX), None);
+}
+
+#[test]
+fn range_size_or_default() {
+    assert_eq!(Ix::range_size_or(-5i32, 5, usize::MAX), 11);
+    assert_eq!(Ix::range_size_or(i128::MIN, i128::MAX, 42), 42);
+    assert_eq!(Ix::range_size_or(0u128, usize::MAX as u128, 42), 42);
+    assert_eq!(Ix::range_size_or(1u128, usize::MAX as u128, 42), usize::MAX);
+}
+
+#[test]
+fn range_size_as_conversions() {
+    assert_eq!(Ix::range_size_as::<u32>(0i32, 1000), Some(1001));
+    assert_eq!(Ix::range_size_as::<u16>(0u32, 65534), Some(u16::MAX));
+    assert_eq!(Ix::range_size_as::<u16>(0u32, 65535), None);
+    assert_eq!(Ix::range_size_as::<u64>(i128::MIN, i128::MAX), None);
+}
+
+#[test]
+fn nonzero_tuple_grid() {
+    let nz8 = |n| NonZeroU8::new(n).unwrap();
+    let (min, max) = ((nz8(2), nz8(10)), (nz8(4), nz8(13)));
+    let (a_size, b_size) = (
+        Ix::range_size(nz8(2), nz8(4)),
+        Ix::range_size(nz8(10), nz8(13)),
+    );
+    assert_eq!((a_size, b_size), (3, 4));
+    assert_eq!(Ix::range_size(min, max), a_size * b_size);
+    assert_eq!(Ix::range(min, max).count(), 12);
+    assert_eq!((nz8(2), nz8(10)).index(min, max), 0);
+    assert_eq!((nz8(3), nz8(11)).index(min, max), 5);
+    assert_eq!((nz8(4), nz8(13)).index(min, max), 11);
+    for (i, ix) in Ix::range(min, max).enumerate() {
+        assert_eq!(ix.index(min, max), i);
+        assert_eq!(Ix::from_index(i, min, max), ix);
+    }
+    assert!(!(nz8(1), nz8(10)).in_range(min, max));
+}
+
+#[test]
+fn nonzero_tuple_full_axes() {
+    let (min, max) = (
+        (NonZeroU8::MIN, NonZeroU8::MIN),
+        (NonZeroU8::MAX, NonZeroU8::MAX),
+    );
+    assert_eq!(Ix::range_size(min, max), 255 * 255);
+    assert_eq!(
+        (NonZeroU8::MAX, NonZeroU8::MAX).index(min, max),
+        255 * 255 - 1
+    );
+    assert_eq!(<(NonZeroU8, NonZeroU8) as BoundedIx>::MIN, min);
+    assert_eq!(
+        Ix::range_size_checked(NonZeroU128::MIN, NonZeroU128::MAX),
+        usize::try_from(u128::MAX).ok()
+    );
+}
@@ -0,0 +1,57 @@
+use core::cmp::Reverse;
+use ix_rs::{BoundedIx, Ix};
+
+#[test]
+fn reverse_descends() {
+    assert!(Ix::range(Reverse(5u8), Reverse(2)).eq([5, 4, 3, 2].map(Reverse)));
+    assert_eq!(Reverse(4u8).index(Reverse(5), Reverse(2)), 1);
+    assert_eq!(Ix::from_index(3, Reverse(5u8), Reverse(2)), Reverse(2));
+    assert_eq!(Ix::from_index_checked(4, Reverse(5u8), Reverse(2)), None);
+    assert_eq!(Reverse(2u8).successor(Reverse(5), Reverse(2)), None);
+    assert_eq!(Reverse(5u8).predecessor(Reverse(5), Reverse(2)), None);
+    assert_eq!(
+        Reverse(5u8).advance(2, Reverse(5), Reverse(2)),
+        Some(Reverse(3))
+    );
+}
+
+#[test]
+fn reverse_rows_ascending_columns() {
+    let (min, max) = ((Reverse(2u8), 0u8), (Reverse(0), 1));
+    let cells: Vec<_> = Ix::range(min, max)
+        .map(|(Reverse(row), col)| (row, col))
+        .collect();
+    assert_eq!(cells, [(2, 0), (2, 1), (1, 0), (1, 1), (0, 0), (0, 1)]);
+    for (i, cell) in Ix::range(min, max).enumerate() {
+        assert_eq!(cell.index(min, max), i);
+        assert_eq!(Ix::from_index(i, min, max), cell);
+    }
+}
+
+#[test]
+fn reverse_full_range() {
+    let (min, max) = (Reverse(u128::MAX), Reverse(0));
+    assert_eq!(Ix::range_size_checked(min, max), None);
+    assert_eq!(Reverse(0u128).index_u128(min, max), u128::MAX);
+    assert_eq!(Reverse(u128::MAX - 3).index_checked(min, max), Some(3));
+    assert_eq!(Ix::from_index(3, min, max), Reverse(u128::MAX - 3));
+}
+
+#[test]
+fn bounded_reverse() {
+    assert!(Reverse::<u8>::range_from(Reverse(2)).eq([2, 1, 0].map(Reverse)));
+    assert_eq!(
+        BoundedIx::pad_bounds(Reverse(5u8), Reverse(2), 3),
+        (Reverse(8), Reverse(0))
+    );
+    assert_eq!(
+        BoundedIx::shrink_bounds(Reverse(5u8), Reverse(2), 1),
+        Some((Reverse(4), Reverse(3)))
+    );
+}
+
+#[test]
+#[should_panic(expected = "min is greater than max")]
+fn reverse_unordered() {
+    Ix::range(Reverse(2u8), Reverse(5));
+}
@@ -1,6 +1,6 @@
-use ix_rs::Ix;
+use ix_rs::{define_ix_newtype, Ix};
 use paste::paste;
-use proptest::{prop_assert, proptest};
+use proptest::{prop_assert, proptest, strategy::Strategy};
 
 fn ix_uphold_1<T: Ix + Copy>(min: T, max: T, ix: T) -> bool {
     if min > max {
@@ -63,6 +63,64 @@ fn ix_uphold_6<T: Ix + Copy + std::panic::RefUnwindSafe>(min: T, max: T) -> bool
         == std::panic::catch_unwind(|| Ix::range(min, max).count()).is_err()
 }
 
+fn ix_from_index<T: Ix + Copy>(min: T, max: T, ix: T) -> bool {
+    if min > max || !ix.in_range(min, max) {
+        return true;
+    }
+    Ix::from_index_checked(ix.index(min, max), min, max) == Some(ix)
+}
+
+fn ix_successor<T: Ix + Copy>(min: T, max: T, ix: T) -> bool {
+    if min > max || !ix.in_range(min, max) {
+        return true;
+    }
+    ix.successor(min, max) == Ix::range(min, max).nth(ix.index(min, max) + 1)
+}
+
+fn ix_predecessor<T: Ix + Copy>(min: T, max: T, ix: T) -> bool {
+    if min > max || !ix.in_range(min, max) {
+        return true;
+    }
+    match ix.index(min, max) {
+        0 => ix.predecessor(min, max).is_none(),
+        i => ix.predecessor(min, max) == Ix::range(min, max).nth(i - 1),
+    }
+}
+
+fn ix_rank_select<T: Ix + Copy>(min: T, max: T, ix: T) -> bool {
+    if min > max || !ix.in_range(min, max) {
+        return true;
+    }
+    Ix::select(ix.rank(min, max), min, max) == ix
+}
+
+fn ix_advance_retreat<T: Ix + Copy>(min: T, max: T, ix: T, n: usize) -> bool {
+    if min > max || !ix.in_range(min, max) {
+        return true;
+    }
+    match ix.advance(n, min, max) {
+        Some(advanced) => advanced.retreat(n, min, max) == Some(ix),
+        None => ix.index(min, max) + n >= Ix::range_size(min, max),
+    }
+}
+
+fn ix_retreat_advance<T: Ix + Copy>(min: T, max: T, ix: T, n: usize) -> bool {
+    if min > max || !ix.in_range(min, max) {
+        return true;
+    }
+    match ix.retreat(n, min, max) {
+        Some(retreated) => retreated.advance(n, min, max) == Some(ix),
+        None => ix.index(min, max) < n,
+    }
+}
+
+fn ix_cmp_by_index<T: Ix + Copy>(min: T, max: T, a: T, b: T) -> bool {
+    if min > max || !a.in_range(min, max) || !b.in_range(min, max) {
+        return true;
+    }
+    a.partial_cmp(&b) == Some(a.cmp_by_index(b, min, max))
+}
+
 macro_rules! r {
     ($t: ty, 0) => {
         -127..=127
@@ -103,6 +161,34 @@ macro_rules! proptest_ix_uphold_some_numeric {
                 fn [<proptest_ix_uphold_6_ $t _ $x>](min in r!($t, $x), max in r!($t, $x)) {
                     prop_assert!(ix_uphold_6(min, max))
                 }
+                #[test]
+                fn [<proptest_ix_from_index_ $t _ $x>](min in r!($t, $x), max in r!($t, $x), ix in r!($t, $x)) {
+                    prop_assert!(ix_from_index(min, max, ix))
+                }
+                #[test]
+                fn [<proptest_ix_successor_ $t _ $x>](min in r!($t, $x), max in r!($t, $x), ix in r!($t, $x)) {
+                    prop_assert!(ix_successor(min, max, ix))
+                }
+                #[test]
+                fn [<proptest_ix_predecessor_ $t _ $x>](min in r!($t, $x), max in r!($t, $x), ix in r!($t, $x)) {
+                    prop_assert!(ix_predecessor(min, max, ix))
+                }
+                #[test]
+                fn [<proptest_ix_rank_select_ $t _ $x>](min in r!($t, $x), max in r!($t, $x), ix in r!($t, $x)) {
+                    prop_assert!(ix_rank_select(min, max, ix))
+                }
+                #[test]
+                fn [<proptest_ix_advance_retreat_ $t _ $x>](min in r!($t, $x), max in r!($t, $x), ix in r!($t, $x), n in 0usize..=255) {
+                    prop_assert!(ix_advance_retreat(min, max, ix, n))
+                }
+                #[test]
+                fn [<proptest_ix_retreat_advance_ $t _ $x>](min in r!($t, $x), max in r!($t, $x), ix in r!($t, $x), n in 0usize..=255) {
+                    prop_assert!(ix_retreat_advance(min, max, ix, n))
+                }
+                #[test]
+                fn [<proptest_ix_cmp_by_index_ $t _ $x>](min in r!($t, $x), max in r!($t, $x), a in r!($t, $x), b in r!($t, $x)) {
+                    prop_assert!(ix_cmp_by_index(min, max, a, b))
+                }
             }
         }
     };
@@ -128,3 +214,102 @@ proptest_ix_uphold_all_numeric!(i32);
 proptest_ix_uphold_all_numeric!(i64);
 proptest_ix_uphold_all_numeric!(i128);
 proptest_ix_uphold_all_numeric!(isize);
+
+define_ix_newtype!(Row, u16);
+define_ix_newtype!(Col, u16);
+
+macro_rules! proptest_ix_uphold_strategy {
+    ($name: ident, $s: expr) => {
+        proptest_ix_uphold_strategy!($name, $s, $s, $s);
+    };
+    ($name: ident, $min: expr, $max: expr, $ix: expr) => {
+        paste! {
+            proptest! {
+                #[test]
+                fn [<proptest_ix_uphold_1_ $name>](min in $min, max in $max, ix in $ix) {
+                    prop_assert!(ix_uphold_1(min, max, ix))
+                }
+                #[test]
+                fn [<proptest_ix_uphold_2_ $name>](min in $min, max in $max, ix in $ix) {
+                    prop_assert!(ix_uphold_2(min, max, ix))
+                }
+                #[test]
+                fn [<proptest_ix_uphold_3_ $name>](min in $min, max in $max) {
+                    prop_assert!(ix_uphold_3(min, max))
+                }
+                #[test]
+                fn [<proptest_ix_uphold_4_ $name>](min in $min, max in $max) {
+                    prop_assert!(ix_uphold_4(min, max))
+                }
+                #[test]
+                fn [<proptest_ix_uphold_5_ $name>](min in $min, max in $max) {
+                    prop_assert!(ix_uphold_5(min, max))
+                }
+                #[test]
+                fn [<proptest_ix_uphold_6_ $name>](min in $min, max in $max) {
+                    prop_assert!(ix_uphold_6(min, max))
+                }
+                #[test]
+                fn [<proptest_ix_from_index_ $name>](min in $min, max in $max, ix in $ix) {
+                    prop_assert!(ix_from_index(min, max, ix))
+                }
+                #[test]
+                fn [<proptest_ix_successor_ $name>](min in $min, max in $max, ix in $ix) {
+                    prop_assert!(ix_successor(min, max, ix))
+                }
+                #[test]
+                fn [<proptest_ix_predecessor_ $name>](min in $min, max in $max, ix in $ix) {
+                    prop_assert!(ix_predecessor(min, max, ix))
+                }
+                #[test]
+                fn [<proptest_ix_cmp_by_index_ $name>](min in $min, max in $max, a in $ix, b in $ix) {
+                    prop_assert!(ix_cmp_by_index(min, max, a, b))
+                }
+            }
+        }
+    };
+}
+
+proptest_ix_uphold_strategy!(row, (0u16..=127).prop_map(Row));
+proptest_ix_uphold_strategy!(col, (0u16..=127).prop_map(Col));
+proptest_ix_uphold_strategy!(char_0, proptest::char::range('\0', '\u{7F}'));
+proptest_ix_uphold_strategy!(char_1, proptest::char::range('\u{D780}', '\u{E07F}'));
+proptest_ix_uphold_strategy!(char_2, proptest::char::range('\u{10FF80}', char::MAX));
+proptest_ix_uphold_strategy!(
+    nonzero_usize,
+    (1usize..=128).prop_map(|n| std::num::NonZeroUsize::new(n).unwrap())
+);
+proptest_ix_uphold_strategy!(reverse, (-127i8..=127).prop_map(std::cmp::Reverse));
+proptest_ix_uphold_strategy!(unit, proptest::strategy::Just(()));
+proptest_ix_uphold_strategy!(single, (-8i8..=8,));
+proptest_ix_uphold_strategy!(
+    pair,
+    (-8i8..=0, 0u16..=3),
+    (0i8..=8, 3u16..=6),
+    (-8i8..=8, 0u16..=6)
+);
+proptest_ix_uphold_strategy!(
+    array,
+    [-4i16..=0, 0i16..=3],
+    [0i16..=4, 3i16..=6],
+    [-4i16..=4, 0i16..=6]
+);
+proptest_ix_uphold_strategy!(empty_array, proptest::strategy::Just([0u8; 0]));
+proptest_ix_uphold_strategy!(
+    triple,
+    (
+        0u8..=2,
+        proptest::char::range('\u{D7FE}', '\u{D7FF}'),
+        -3i64..=0
+    ),
+    (
+        2u8..=4,
+        proptest::char::range('\u{D7FF}', '\u{E001}'),
+        0i64..=3
+    ),
+    (
+        0u8..=4,
+        proptest::char::range('\u{D7FE}', '\u{E001}'),
+        -3i64..=3
+    )
+);
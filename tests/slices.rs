@@ -0,0 +1,91 @@
+use ix_rs::{Ix, LengthMismatch, OutOfRange};
+
+#[test]
+fn fill_slice_contents() {
+    let mut out = [0i32; 5];
+    Ix::fill_slice(10, 14, &mut out);
+    assert_eq!(out, [10, 11, 12, 13, 14]);
+    let mut out = [(0u8, 0u8); 4];
+    Ix::fill_slice((0, 0), (1, 1), &mut out);
+    assert_eq!(out, [(0, 0), (0, 1), (1, 0), (1, 1)]);
+}
+
+#[test]
+fn try_fill_slice_mismatch() {
+    let mut out = [7i32; 4];
+    assert_eq!(
+        Ix::try_fill_slice(10, 14, &mut out),
+        Err(LengthMismatch {
+            expected: Some(5),
+            actual: 4
+        })
+    );
+    assert_eq!(out, [7; 4]);
+    assert_eq!(
+        Ix::try_fill_slice(0, u128::MAX, &mut [0; 2]),
+        Err(LengthMismatch {
+            expected: None,
+            actual: 2
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "slice length 6 differs from range size 5")]
+fn fill_slice_mismatch() {
+    Ix::fill_slice(10i32, 14, &mut [0; 6]);
+}
+
+#[test]
+fn gather_into_squares() {
+    let mut dst = [0i64; 5];
+    Ix::gather_into((0i32, 4), &mut dst, |x| i64::from(x) * i64::from(x));
+    assert_eq!(dst, [0, 1, 4, 9, 16]);
+    let mut dst = [' '; 3];
+    Ix::gather_into((0u8, 2), &mut dst, |x| char::from(b'a' + x));
+    assert_eq!(dst, ['a', 'b', 'c']);
+}
+
+#[test]
+#[should_panic(expected = "slice length 4 differs from range size 5")]
+fn gather_into_length_mismatch() {
+    Ix::gather_into((0i32, 4), &mut [0i64; 4], i64::from);
+}
+
+#[test]
+fn index_batch_valid() {
+    let mut out = [usize::MAX; 4];
+    assert_eq!(Ix::index_batch(&[-5i32, 5, 0, -1], -5, 5, &mut out), Ok(()));
+    assert_eq!(out, [0, 10, 5, 4]);
+    let mut out = [0; 2];
+    let values = [(1u8, 1u8), (2, 0)];
+    assert_eq!(Ix::index_batch(&values, (0, 0), (2, 2), &mut out), Ok(()));
+    assert_eq!(out, [4, 6]);
+    assert_eq!(Ix::index_batch(&[], 0u8, 0, &mut []), Ok(()));
+}
+
+#[test]
+fn index_batch_out_of_range() {
+    let mut out = [0; 4];
+    assert_eq!(
+        Ix::index_batch(&[3i32, 4, -7, 12], -5, 5, &mut out),
+        Err(OutOfRange::BelowMin(-7))
+    );
+    assert_eq!(out[..2], [8, 9]);
+}
+
+#[test]
+#[should_panic(expected = "output length differs from input length")]
+fn index_batch_length_mismatch() {
+    let _ = Ix::index_batch(&[1i32, 2, 3], 0, 9, &mut [0; 2]);
+}
+
+#[test]
+fn shape_fits_products() {
+    assert!(Ix::shape_fits((1i32, 3), (10, 13), 12));
+    assert!(!Ix::shape_fits((1i32, 3), (10, 13), 11));
+    assert!(!Ix::shape_fits((1i32, 3), (10, 13), 16));
+    assert!(Ix::shape_fits(((0u8, 0u8), (1, 1)), ((0, 0), (0, 2)), 12));
+    assert!(!Ix::shape_fits((0u64, u64::MAX / 2), (0, 3), 0));
+    assert!(!Ix::shape_fits((0u128, u128::MAX), (0, 0), usize::MAX));
+}
@@ -0,0 +1,149 @@
+#![cfg(feature = "testing")]
+
+use ix_rs::{validate, Ix, PropertyViolation};
+
+/// Define a wrapper around `u8` whose `Ix` implementation is broken in one way.
+macro_rules! mock {
+    ($name: ident, $range: expr, $index: expr, $in_range: expr, $size: expr) => {
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        struct $name(u8);
+
+        impl Ix for $name {
+            type Range = Box<dyn Iterator<Item = $name>>;
+            fn range(min: Self, max: Self) -> Self::Range {
+                let range: fn(u8, u8) -> Box<dyn Iterator<Item = u8>> = $range;
+                Box::new(range(min.0, max.0).map($name))
+            }
+            fn index_checked(self, min: Self, max: Self) -> Option<usize> {
+                let index: fn(u8, u8, u8) -> Option<usize> = $index;
+                index(self.0, min.0, max.0)
+            }
+            fn in_range(self, min: Self, max: Self) -> bool {
+                let in_range: fn(u8, u8, u8) -> bool = $in_range;
+                in_range(self.0, min.0, max.0)
+            }
+            fn range_size_checked(min: Self, max: Self) -> Option<usize> {
+                let size: fn(u8, u8) -> Option<usize> = $size;
+                size(min.0, max.0)
+            }
+        }
+    };
+}
+
+mock!(
+    Correct,
+    |min, max| Box::new(min..=max),
+    |ix, min, _| Some(usize::from(ix - min)),
+    |ix, min, max| min <= ix && ix <= max,
+    |min, max| Some(usize::from(max - min) + 1)
+);
+mock!(
+    BadInRange,
+    |min, max| Box::new(min..=max),
+    |ix, min, _| Some(usize::from(ix - min)),
+    |_, _, _| true,
+    |min, max| Some(usize::from(max - min) + 1)
+);
+mock!(
+    BadIndex,
+    |min, max| Box::new(min..=max),
+    |ix, min, _| Some(usize::from(ix - min) + 1),
+    |ix, min, max| min <= ix && ix <= max,
+    |min, max| Some(usize::from(max - min) + 1)
+);
+mock!(
+    BadSizeNone,
+    |min, max| Box::new(min..=max),
+    |ix, min, _| Some(usize::from(ix - min)),
+    |ix, min, max| min <= ix && ix <= max,
+    |_, _| None
+);
+mock!(
+    BadSize,
+    |min, max| Box::new(min..=max),
+    |ix, min, _| Some(usize::from(ix - min)),
+    |ix, min, max| min <= ix && ix <= max,
+    |min, max| Some(usize::from(max - min) + 2)
+);
+mock!(
+    BadRange,
+    |min, max| Box::new((min..=max).chain(std::iter::from_fn(|| panic!("exhausted")))),
+    |ix, min, _| Some(usize::from(ix - min)),
+    |ix, min, max| min <= ix && ix <= max,
+    |min, max| Some(usize::from(max - min) + 1)
+);
+
+#[test]
+fn validate_correct() {
+    for ix in 0..=10 {
+        assert_eq!(validate(Correct(2), Correct(8), Correct(ix)), Ok(()));
+    }
+    assert_eq!(validate(Correct(8), Correct(2), Correct(5)), Ok(()));
+}
+
+#[test]
+fn validate_in_range() {
+    assert_eq!(
+        validate(BadInRange(2), BadInRange(8), BadInRange(9)),
+        Err(PropertyViolation::InRange {
+            ix: BadInRange(9),
+            in_range: true
+        })
+    );
+}
+
+#[test]
+fn validate_index() {
+    assert_eq!(
+        validate(BadIndex(2), BadIndex(8), BadIndex(8)),
+        Err(PropertyViolation::Index {
+            ix: BadIndex(8),
+            index: 7,
+            found: None
+        })
+    );
+}
+
+#[test]
+fn validate_enumeration() {
+    assert_eq!(
+        validate(BadIndex(2), BadIndex(8), BadIndex(9)),
+        Err(PropertyViolation::Enumeration {
+            position: 0,
+            index: Some(1)
+        })
+    );
+}
+
+#[test]
+fn validate_checked_index() {
+    assert_eq!(
+        validate(BadSizeNone(2), BadSizeNone(8), BadSizeNone(5)),
+        Err(PropertyViolation::CheckedIndex { size: None })
+    );
+}
+
+#[test]
+fn validate_size() {
+    assert_eq!(
+        validate(BadSize(2), BadSize(8), BadSize(5)),
+        Err(PropertyViolation::Size { size: 8, count: 7 })
+    );
+}
+
+#[test]
+fn validate_overflow() {
+    assert_eq!(
+        validate(BadRange(2), BadRange(8), BadRange(5)),
+        Err(PropertyViolation::Overflow { size: Some(7) })
+    );
+}
+
+#[test]
+fn violation_display() {
+    let violation: PropertyViolation<u8> = PropertyViolation::Size { size: 8, count: 7 };
+    assert_eq!(
+        violation.to_string(),
+        "invariant 5 violated: range size is 8, but the range has 7 elements"
+    );
+}